@@ -132,12 +132,16 @@ pub(crate) fn bot_command_inner(input: DeriveInput) -> TokenStream {
     quote! {
         impl tgbotool::command::BotCommand for #enum_ident {
             fn parse(message: &str) -> Result<Self, tgbotool::command::Error> {
+                Self::parse_with_bot_name(message, #bot_name)
+            }
+
+            fn parse_with_bot_name(message: &str, bot_name: &str) -> Result<Self, tgbotool::command::Error> {
                 use tgbotool::command;
                 let mut words = message.split_ascii_whitespace();
                 let mut command_and_bot_name = words.next().ok_or(command::Error::TooFewArgs)?.split('@');
                 let command = command_and_bot_name.next().ok_or(command::Error::TooFewArgs)?;
                 if let Some(cmd_bot_name) = command_and_bot_name.next() {
-                    if cmd_bot_name != #bot_name {
+                    if cmd_bot_name != bot_name {
                         return Err(command::Error::WrongBotName);
                     }
                 }
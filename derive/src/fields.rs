@@ -72,6 +72,8 @@ pub(crate) fn get_fields(struct_fields: &Fields) -> Vec<TgField<'_>> {
                                 multipart = Some(MultipartType::Normal);
                             } else if path.is_ident("attach") {
                                 multipart = Some(MultipartType::Attach);
+                            } else if path.is_ident("input") {
+                                multipart = Some(MultipartType::Input);
                             } else {
                                 panic!("unsupported multipart attr")
                             }
@@ -1,13 +1,15 @@
 use heck::ToLowerCamelCase;
 use proc_macro2::TokenStream;
-use quote::quote;
-use syn::{Data, DeriveInput};
+use quote::{format_ident, quote};
+use syn::{parse::ParseStream, Data, DeriveInput, Token};
 
-use crate::fields::get_fields;
+use crate::{fields::get_fields, parser::parse_lit_str};
 
 pub enum MultipartType {
     Normal,
     Attach,
+    /// input objects (`Vec<InputSticker>`, `Vec<Media>`...) with files sent as `attach://<file_attach_name>`
+    Input,
 }
 
 pub(crate) fn multipart_inner(input: DeriveInput) -> TokenStream {
@@ -15,38 +17,74 @@ pub(crate) fn multipart_inner(input: DeriveInput) -> TokenStream {
         panic!("current only support struct");
     };
     let struct_ident = input.ident;
+    // #[multipart(validate = "method")], called before the request is sent
+    let mut validate = None;
+    for attr in &input.attrs {
+        if !attr.path().is_ident("multipart") {
+            continue;
+        }
+        let attrs = attr
+            .parse_args_with(|input: ParseStream| input.parse_terminated(parse_lit_str, Token![,]))
+            .unwrap();
+        for (key, value) in attrs {
+            match key.to_string().as_str() {
+                "validate" => validate = Some(format_ident!("{}", value.value())),
+                k => panic!("unsupported multipart attr: {k}"),
+            }
+        }
+    }
+    let validate_method = match validate {
+        Some(validate) => quote! {
+            fn validate(&self) -> Result<(), crate::client::Error> {
+                Ok(self.#validate()?)
+            }
+        },
+        None => quote! {},
+    };
     let fields = get_fields(&struct_data.fields);
     let methods = fields.iter().map(|f| {
         let fident = f.ident;
         let fident_str = fident.to_string();
         let raw_fident = fident;
-        let fident = if f.is_option || f.is_vec {
+        let fident = if f.is_option {
             quote! {#fident}
         } else {
             quote! {this.#fident}
         };
         let normal = quote! {
-            form = form.text(#fident_str, json::to_string(&#fident)?);
+            form = form.text(#fident_str, crate::methods::form_value(&#fident)?);
         };
         let normal_str = quote! {
             form = form.text(#fident_str, #fident);
         };
         let normal_multipart = quote! {
-            form = form.part(#fident_str, #fident.into());
+            form = form.part(#fident_str, crate::methods::SendFile::into_part(#fident)?);
         };
         let attach_multipart = quote! {
-            for part in Into::<Vec<crate::methods::FilePart>>::into(#fident) {
+            for part in crate::methods::SendFile::into_attach_parts(#fident, &mut attach_names)? {
                 match part {
                     crate::methods::FilePart::Simple(part) => form = form.part(#fident_str, part),
                     crate::methods::FilePart::Complex((name, part)) => form = form.part(name, part),
                 }
             }
         };
-        match (f.is_option, f.is_str) {
+        let input_multipart = quote! {
+            for (name, part) in crate::methods::AttachInput::take_file_parts(&mut #raw_fident, &mut attach_names)? {
+                form = form.part(name, part);
+            }
+            form = form.text(#fident_str, serde_json::to_string(&#raw_fident)?);
+        };
+        // Vec<String> is serialized as json
+        let is_str = f.is_str && (f.is_option || f.inner_ty.is_none());
+        match (f.is_option, is_str) {
             (false, false) => match &f.multipart {
                 Some(multipart) => match multipart {
                     MultipartType::Normal => normal_multipart,
                     MultipartType::Attach => attach_multipart,
+                    MultipartType::Input => quote! {
+                        let mut #raw_fident = this.#raw_fident;
+                        #input_multipart
+                    },
                 },
                 None => normal,
             },
@@ -67,6 +105,11 @@ pub(crate) fn multipart_inner(input: DeriveInput) -> TokenStream {
                             #attach_multipart
                         }
                     },
+                    MultipartType::Input => quote! {
+                        if let Some(mut #raw_fident) = this.#raw_fident {
+                            #input_multipart
+                        }
+                    },
                 },
                 None => quote! {
                     if let Some(#raw_fident) = this.#raw_fident {
@@ -81,17 +124,26 @@ pub(crate) fn multipart_inner(input: DeriveInput) -> TokenStream {
         .iter()
         .filter_map(|f| {
             let fident = f.ident;
-            f.multipart.as_ref().map(|_| {
-                if f.is_option {
-                    quote! {
-                        matches!(self.#fident, Some(super::SendFile::UploadInput { .. }))
-                    }
-                } else {
-                    quote! {
-                        matches!(self.#fident, super::SendFile::UploadInput { .. })
-                    }
-                }
-            })
+            f.multipart
+                .as_ref()
+                .map(|multipart| match (multipart, f.is_option) {
+                    (MultipartType::Input, false) => quote! {
+                        crate::methods::AttachInput::is_multipart(&self.#fident)
+                    },
+                    (MultipartType::Input, true) => quote! {
+                        self.#fident
+                            .as_ref()
+                            .is_some_and(crate::methods::AttachInput::is_multipart)
+                    },
+                    (_, true) => quote! {
+                        self.#fident
+                            .as_ref()
+                            .is_some_and(crate::methods::SendFile::is_upload)
+                    },
+                    (_, false) => quote! {
+                        self.#fident.is_upload()
+                    },
+                })
         })
         .peekable();
     let is_multi_method = if is_multi_method_content.peek().is_some() {
@@ -105,13 +157,23 @@ pub(crate) fn multipart_inner(input: DeriveInput) -> TokenStream {
     } else {
         quote! {}
     };
+    // attach names are unique across all attach and input fields
+    let attach_names = fields
+        .iter()
+        .any(|f| {
+            matches!(
+                f.multipart,
+                Some(MultipartType::Attach | MultipartType::Input)
+            )
+        })
+        .then(|| quote! { let mut attach_names = crate::methods::AttachNames::default(); });
     let method_name = struct_ident.to_string().to_lower_camel_case();
     quote! {
         impl TryFrom<#struct_ident> for reqwest::multipart::Form {
-            type Error = serde_json::Error;
+            type Error = crate::client::Error;
             fn try_from(this: #struct_ident) -> Result<Self, Self::Error> {
-                use serde_json as json;
                 let mut form = reqwest::multipart::Form::new();
+                #attach_names
 
                 #(
                     #methods
@@ -127,6 +189,8 @@ pub(crate) fn multipart_inner(input: DeriveInput) -> TokenStream {
             }
 
             #is_multi_method
+
+            #validate_method
         }
     }
 }
@@ -2,6 +2,7 @@ use std::time::Duration;
 
 use tgbotool::{methods::get_updates::GetUpdatesBuilder, types::update::Update};
 
+#[allow(dead_code)]
 #[derive(tgbotool_derive::BotCommand)]
 enum Command {
     Start,
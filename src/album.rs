@@ -0,0 +1,241 @@
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use tokio::sync::mpsc;
+
+use crate::types::{
+    message::Message,
+    update::{Update, UpdateType},
+};
+
+/// telegram delivers the messages of an album within a few hundred milliseconds
+pub const DEFAULT_WINDOW: Duration = Duration::from_millis(800);
+
+/// Messages (or channel posts) sharing the same `media_group_id`
+pub struct MediaGroup {
+    pub media_group_id: String,
+    pub is_channel_post: bool,
+    /// ordered by message_id
+    pub messages: Vec<Message>,
+}
+
+#[allow(clippy::large_enum_variant)]
+pub enum AlbumEvent {
+    /// not part of an album
+    Update(Update),
+    MediaGroup(MediaGroup),
+}
+
+struct PendingGroup {
+    group: MediaGroup,
+    deadline: Instant,
+}
+
+/// Buffers album messages until no new item arrived within the window.
+///
+/// The collector only keeps the state, it is driven by the caller (see [`collect_albums`]).
+pub struct AlbumCollector {
+    window: Duration,
+    pending: HashMap<String, PendingGroup>,
+}
+
+impl AlbumCollector {
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            pending: HashMap::new(),
+        }
+    }
+
+    /// Buffer album messages, other updates are returned as they are
+    pub fn push(&mut self, update: Update, now: Instant) -> Option<AlbumEvent> {
+        let (message, is_channel_post) = match update.update_type {
+            UpdateType::Message(message) if message.media_group_id.is_some() => (message, false),
+            UpdateType::ChannelPost(message) if message.media_group_id.is_some() => (message, true),
+            update_type => {
+                return Some(AlbumEvent::Update(Update {
+                    update_id: update.update_id,
+                    update_type,
+                }))
+            }
+        };
+        let media_group_id = message.media_group_id.clone().unwrap_or_default();
+        let pending = self
+            .pending
+            .entry(media_group_id.clone())
+            .or_insert_with(|| PendingGroup {
+                group: MediaGroup {
+                    media_group_id,
+                    is_channel_post,
+                    messages: Vec::new(),
+                },
+                deadline: now,
+            });
+        pending.group.messages.push(message);
+        pending.deadline = now + self.window;
+        None
+    }
+
+    /// When the next album is complete
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.pending.values().map(|pending| pending.deadline).min()
+    }
+
+    /// Albums without new items since the window, oldest first
+    pub fn flush_expired(&mut self, now: Instant) -> Vec<MediaGroup> {
+        let expired = self
+            .pending
+            .iter()
+            .filter(|(_, pending)| pending.deadline <= now)
+            .map(|(media_group_id, _)| media_group_id.clone())
+            .collect::<Vec<_>>();
+        let mut groups = expired
+            .into_iter()
+            .filter_map(|media_group_id| self.pending.remove(&media_group_id))
+            .collect::<Vec<_>>();
+        groups.sort_by_key(|pending| pending.deadline);
+        groups
+            .into_iter()
+            .map(|pending| finish(pending.group))
+            .collect()
+    }
+
+    /// All buffered albums, e.g. when the update stream ends
+    pub fn flush_all(&mut self) -> Vec<MediaGroup> {
+        let mut groups = self
+            .pending
+            .drain()
+            .map(|(_, pending)| pending)
+            .collect::<Vec<_>>();
+        groups.sort_by_key(|pending| pending.deadline);
+        groups
+            .into_iter()
+            .map(|pending| finish(pending.group))
+            .collect()
+    }
+}
+
+fn finish(mut group: MediaGroup) -> MediaGroup {
+    group.messages.sort_by_key(|message| message.message_id);
+    group
+}
+
+/// Merge albums of the update stream into `AlbumEvent::MediaGroup`.
+///
+/// Must be called within a tokio runtime, the returned stream ends after `updates` is closed.
+pub fn collect_albums(
+    mut updates: mpsc::Receiver<Update>,
+    window: Duration,
+) -> mpsc::Receiver<AlbumEvent> {
+    let (tx, rx) = mpsc::channel(updates.max_capacity());
+    tokio::spawn(async move {
+        let mut collector = AlbumCollector::new(window);
+        loop {
+            let deadline = collector.next_deadline();
+            let update = tokio::select! {
+                update = updates.recv() => update,
+                _ = sleep_until(deadline) => {
+                    for group in collector.flush_expired(Instant::now()) {
+                        if tx.send(AlbumEvent::MediaGroup(group)).await.is_err() {
+                            return;
+                        }
+                    }
+                    continue;
+                }
+            };
+            let Some(update) = update else {
+                break;
+            };
+            if let Some(event) = collector.push(update, Instant::now()) {
+                if tx.send(event).await.is_err() {
+                    return;
+                }
+            }
+        }
+        for group in collector.flush_all() {
+            if tx.send(AlbumEvent::MediaGroup(group)).await.is_err() {
+                return;
+            }
+        }
+    });
+    rx
+}
+
+async fn sleep_until(deadline: Option<Instant>) {
+    match deadline {
+        Some(deadline) => tokio::time::sleep_until(deadline.into()).await,
+        None => std::future::pending().await,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::message::fixture::message_json;
+
+    fn update(update_id: u64, message_id: u64, media_group_id: Option<&str>) -> Update {
+        serde_json::from_value(serde_json::json!({
+            "update_id": update_id,
+            "message": message_json(message_id, serde_json::json!({"media_group_id": media_group_id}))
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn collect_album() {
+        let window = Duration::from_millis(500);
+        let mut collector = AlbumCollector::new(window);
+        let start = Instant::now();
+
+        assert!(collector.push(update(1, 11, Some("a")), start).is_none());
+        assert!(collector.push(update(2, 10, Some("a")), start).is_none());
+        assert!(matches!(
+            collector.push(update(3, 12, None), start),
+            Some(AlbumEvent::Update(Update { update_id: 3, .. }))
+        ));
+        let later = start + Duration::from_millis(300);
+        assert!(collector.push(update(4, 13, Some("a")), later).is_none());
+
+        // the window restarts with every new item
+        assert!(collector.flush_expired(start + window).is_empty());
+        assert_eq!(collector.next_deadline(), Some(later + window));
+
+        let groups = collector.flush_expired(later + window);
+        let [group] = &groups[..] else {
+            panic!("expected one album")
+        };
+        assert_eq!(group.media_group_id, "a");
+        assert_eq!(
+            group
+                .messages
+                .iter()
+                .map(|message| message.message_id)
+                .collect::<Vec<_>>(),
+            [10, 11, 13]
+        );
+        assert!(collector.next_deadline().is_none());
+    }
+
+    #[tokio::test]
+    async fn collect_album_stream() {
+        let (tx, rx) = mpsc::channel(16);
+        let mut events = collect_albums(rx, Duration::from_millis(50));
+        tx.send(update(1, 10, Some("a"))).await.unwrap();
+        tx.send(update(2, 11, None)).await.unwrap();
+        tx.send(update(3, 12, Some("a"))).await.unwrap();
+
+        assert!(matches!(
+            events.recv().await,
+            Some(AlbumEvent::Update(Update { update_id: 2, .. }))
+        ));
+        let Some(AlbumEvent::MediaGroup(group)) = events.recv().await else {
+            panic!("expected an album")
+        };
+        assert_eq!(group.messages.len(), 2);
+
+        drop(tx);
+        assert!(events.recv().await.is_none());
+    }
+}
@@ -1,8 +1,16 @@
-use std::sync::Arc;
+use std::{path::Path, sync::Arc, time::Duration};
+
+use tokio::io::{AsyncWrite, AsyncWriteExt};
 
 use crate::{
-    methods::{get_file::GetFile, TgMethod, TgMultipartMethod},
-    types::File,
+    methods::{
+        chat_action::SendChatAction,
+        get_file::GetFile,
+        media_group::{self, Media, MediaGroupError, SendMediaGroupBuilder},
+        user_profile_photos::GetUserProfilePhotosBuilder,
+        ChatId, SendFileError, TgMethod, TgMultipartMethod,
+    },
+    types::{message::Message, user::UserProfilePhotos, File},
 };
 
 type Result<T, E = Error> = std::result::Result<T, E>;
@@ -15,6 +23,20 @@ pub enum Error {
     Response(String),
     #[error("I/O error: {0}")]
     Io(String),
+    #[error("File path missing: {0}")]
+    FilePathMissing(String),
+    #[error("File size mismatch: expected {expected} bytes, got {actual}")]
+    SizeMismatch { expected: u64, actual: u64 },
+    #[error("Invalid media group: {0}")]
+    MediaGroup(#[from] MediaGroupError),
+    #[error("Invalid file: {0}")]
+    SendFile(#[from] SendFileError),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Self::Io(value.to_string())
+    }
 }
 
 impl From<reqwest::Error> for Error {
@@ -49,6 +71,9 @@ pub struct ErrResponse {
     description: String,
 }
 
+/// Telegram clears the chat action after 5 seconds or when a message arrives
+const CHAT_ACTION_INTERVAL: Duration = Duration::from_secs(4);
+
 #[derive(Clone)]
 pub struct Client {
     tg_url: String,
@@ -99,6 +124,18 @@ impl Client {
         self.get_response(resp).await
     }
 
+    /// Send the method as json by reference, e.g. to retry with an upload on failure
+    pub(crate) async fn send_media_json<T, R>(&self, body: &T) -> Result<R, Error>
+    where
+        T: TgMultipartMethod,
+        R: serde::de::DeserializeOwned,
+    {
+        body.validate()?;
+        let url = format!("{}/{}", self.tg_url, T::method_name());
+        let resp = self.client.post(url).json(body).send().await?;
+        self.get_response(resp).await
+    }
+
     async fn build_request<T>(&self, body: T) -> Result<reqwest::Response>
     where
         T: TgMethod,
@@ -111,10 +148,15 @@ impl Client {
     where
         T: TgMultipartMethod,
     {
+        body.validate()?;
         let url = format!("{}/{}", self.tg_url, T::method_name());
         let mut req_builder = self.client.post(url);
         if body.is_multipart() {
-            req_builder = req_builder.multipart(body.try_into()?);
+            // path uploads open the file while building the form
+            let form = tokio::task::spawn_blocking(move || body.try_into())
+                .await
+                .map_err(|e| Error::Io(e.to_string()))??;
+            req_builder = req_builder.multipart(form);
         } else {
             req_builder = req_builder.json(&body);
         }
@@ -149,21 +191,183 @@ impl Client {
         Ok(())
     }
 
-    pub async fn get_file(&self, body: GetFile) -> Result<Option<Vec<u8>>> {
+    /// Download the whole file into memory, see [`Client::download_file`] for large files
+    pub async fn get_file(&self, body: GetFile) -> Result<Vec<u8>> {
         let file: File = self.send(body).await?;
-        let Some(file_path) = file.file_path else {
-            return Ok(None);
+        let mut bytes = Vec::new();
+        self.download_file(&file, &mut bytes).await?;
+        Ok(bytes)
+    }
+
+    /// Stream the file into `writer`, returns the number of bytes written
+    pub async fn download_file<W>(&self, file: &File, writer: W) -> Result<u64>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        self.download_file_with_progress(file, writer, |_, _| {})
+            .await
+    }
+
+    /// Stream the file into `writer`, `progress(downloaded, total)` is called after each chunk
+    pub async fn download_file_with_progress<W, F>(
+        &self,
+        file: &File,
+        mut writer: W,
+        mut progress: F,
+    ) -> Result<u64>
+    where
+        W: AsyncWrite + Unpin,
+        F: FnMut(u64, Option<u64>),
+    {
+        // the path is only missing if the file is too big (more than 20 MB) to be downloaded
+        let Some(file_path) = &file.file_path else {
+            return Err(Error::FilePathMissing(file.file_id.clone()));
         };
         let download_url = format!(
             "https://api.telegram.org/file/bot{}/{}",
             self.bot_token, file_path
         );
-        let resp = self.client.get(download_url).send().await?;
+        let mut resp = self.client.get(download_url).send().await?;
         let status = resp.status();
         if !status.is_success() {
             return Err(Error::Response(format!("download file error: {}", status)));
         }
 
-        Ok(Some(resp.bytes().await?.to_vec()))
+        let total = file
+            .file_size
+            .and_then(|size| u64::try_from(size).ok())
+            .or(resp.content_length());
+        let mut downloaded = 0;
+        while let Some(chunk) = resp.chunk().await? {
+            writer.write_all(&chunk).await?;
+            downloaded += chunk.len() as u64;
+            progress(downloaded, total);
+        }
+        writer.flush().await?;
+
+        match total {
+            Some(expected) if expected != downloaded => Err(Error::SizeMismatch {
+                expected,
+                actual: downloaded,
+            }),
+            _ => Ok(downloaded),
+        }
+    }
+
+    /// Stream the file to `path`, the partially written file is removed on failure
+    pub async fn download_to_path<P>(&self, file: &File, path: P) -> Result<u64>
+    where
+        P: AsRef<Path>,
+    {
+        self.download_to_path_with_progress(file, path, |_, _| {})
+            .await
+    }
+
+    /// Stream the file to `path`, `progress(downloaded, total)` is called after each chunk
+    pub async fn download_to_path_with_progress<P, F>(
+        &self,
+        file: &File,
+        path: P,
+        progress: F,
+    ) -> Result<u64>
+    where
+        P: AsRef<Path>,
+        F: FnMut(u64, Option<u64>),
+    {
+        let path = path.as_ref();
+        let mut writer = tokio::io::BufWriter::new(tokio::fs::File::create(path).await?);
+        let res = self
+            .download_file_with_progress(file, &mut writer, progress)
+            .await;
+        if res.is_err() {
+            drop(writer);
+            let _ = tokio::fs::remove_file(path).await;
+        }
+        res
+    }
+
+    pub async fn has_profile_photo(&self, user_id: u64) -> Result<bool> {
+        let photos: UserProfilePhotos = self
+            .send(GetUserProfilePhotosBuilder::new(user_id).limit(1).build())
+            .await?;
+        Ok(photos.total_count > 0)
+    }
+
+    /// Download the largest size of the user's latest `limit` profile photos
+    pub async fn download_profile_photos(&self, user_id: u64, limit: u32) -> Result<Vec<Vec<u8>>> {
+        let photos: UserProfilePhotos = self
+            .send(
+                GetUserProfilePhotosBuilder::new(user_id)
+                    .limit(limit)
+                    .build(),
+            )
+            .await?;
+        let mut downloads = Vec::new();
+        for photo in photos.largest_photos() {
+            downloads.push(self.get_file(GetFile::new(&photo.file_id)).await?);
+        }
+        Ok(downloads)
+    }
+
+    /// Send any number of media as albums of at most 10 items, sent one after another
+    pub async fn send_album(&self, chat_id: ChatId, media: Vec<Media>) -> Result<Vec<Message>> {
+        media_group::validate_media(&media)?;
+        let mut messages = Vec::new();
+        for chunk in media_group::split_media(media)? {
+            let body = SendMediaGroupBuilder::new(chat_id.clone(), chunk).build();
+            let sent: Vec<Message> = self.send_media(body).await?;
+            messages.extend(sent);
+        }
+        Ok(messages)
+    }
+
+    /// Keep sending the chat action until the returned guard is dropped,
+    /// e.g. show "sending document..." while the document is being generated.
+    ///
+    /// Must be called within a tokio runtime.
+    pub fn keep_chat_action(&self, body: SendChatAction) -> ChatActionGuard {
+        let client = self.clone();
+        let handle = tokio::spawn(async move {
+            let mut interval = tokio::time::interval(CHAT_ACTION_INTERVAL);
+            loop {
+                interval.tick().await;
+                // a failed refresh is not worth interrupting the actual work
+                let _ = client.send_ok(body.clone()).await;
+            }
+        });
+        ChatActionGuard { handle }
+    }
+}
+
+/// Stops refreshing the chat action on drop
+pub struct ChatActionGuard {
+    handle: tokio::task::JoinHandle<()>,
+}
+
+impl Drop for ChatActionGuard {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn download_without_file_path() {
+        let client = Client::new("token", reqwest::Client::new());
+        let file = File {
+            file_id: "big".to_owned(),
+            file_unique_id: "b".to_owned(),
+            file_size: Some(30 * 1024 * 1024),
+            file_path: None,
+        };
+        let mut bytes = Vec::new();
+        assert!(matches!(
+            client.download_file(&file, &mut bytes).await,
+            Err(Error::FilePathMissing(file_id)) if file_id == "big"
+        ));
+        assert!(bytes.is_empty());
     }
 }
@@ -1,7 +1,21 @@
 use std::str::{FromStr, SplitAsciiWhitespace};
 
 pub trait BotCommand: Sized {
+    /// check `/command@bot_name` against `#[command(bot_name = "...")]`
     fn parse(message: &str) -> Result<Self, Error>;
+
+    /// check `/command@bot_name` against the bot username, e.g. `User::username` from `GetMe`
+    fn parse_with_bot_name(message: &str, bot_name: &str) -> Result<Self, Error> {
+        let command = message
+            .split_ascii_whitespace()
+            .next()
+            .ok_or(Error::TooFewArgs)?;
+        match command.split_once('@') {
+            Some((_, name)) if name != bot_name => Err(Error::WrongBotName),
+            Some((cmd, _)) => Self::parse(&message.replacen(command, cmd, 1)),
+            None => Self::parse(message),
+        }
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -29,3 +43,63 @@ where
         .parse::<T>()
         .map_err(|e| Error::ParseError(e.to_string()))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, tgbotool_derive::BotCommand)]
+    #[command(bot_name = "weather_bot", rename_rule = "lowercase")]
+    enum Command {
+        Forecast { city: String },
+    }
+
+    /// implemented by hand, uses the default `parse_with_bot_name`
+    #[derive(Debug, PartialEq)]
+    struct Start;
+
+    impl BotCommand for Start {
+        fn parse(message: &str) -> Result<Self, Error> {
+            match message {
+                "/start" => Ok(Start),
+                _ => Err(Error::UnknownCmd),
+            }
+        }
+    }
+
+    #[test]
+    fn parse_bot_name() {
+        let forecast = Command::Forecast {
+            city: "Berlin".to_owned(),
+        };
+        assert_eq!(
+            Command::parse("/forecast@weather_bot Berlin").unwrap(),
+            forecast
+        );
+        assert!(matches!(
+            Command::parse("/forecast@renamed_bot Berlin"),
+            Err(Error::WrongBotName)
+        ));
+        assert_eq!(
+            Command::parse_with_bot_name("/forecast@renamed_bot Berlin", "renamed_bot").unwrap(),
+            forecast
+        );
+        assert!(matches!(
+            Command::parse_with_bot_name("/forecast@weather_bot Berlin", "renamed_bot"),
+            Err(Error::WrongBotName)
+        ));
+
+        assert_eq!(
+            Start::parse_with_bot_name("/start@my_bot", "my_bot").unwrap(),
+            Start
+        );
+        assert_eq!(
+            Start::parse_with_bot_name("/start", "my_bot").unwrap(),
+            Start
+        );
+        assert!(matches!(
+            Start::parse_with_bot_name("/start@other_bot", "my_bot"),
+            Err(Error::WrongBotName)
+        ));
+    }
+}
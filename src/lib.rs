@@ -1,9 +1,23 @@
 #![allow(dead_code)]
 
+pub mod album;
 pub mod client;
 pub mod command;
+pub mod login;
+pub mod media_cache;
 pub mod methods;
+pub mod pagination;
+pub mod passport;
+pub mod payment_handler;
+pub mod profile;
 pub mod types;
+pub mod web_app;
+
+mod signature;
+
+// the derive macros refer to `tgbotool::`
+#[cfg(test)]
+extern crate self as tgbotool;
 
 pub use client::Client;
 pub use tgbotool_derive::*;
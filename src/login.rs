@@ -0,0 +1,154 @@
+use std::{collections::BTreeMap, time::Duration};
+
+use serde_with::skip_serializing_none;
+use sha2::{Digest, Sha256};
+
+use crate::signature;
+pub use crate::signature::Error;
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// User authorized with the Telegram Login Widget or a `LoginUrl` button
+#[skip_serializing_none]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct LoginData {
+    pub id: u64,
+    pub first_name: String,
+    pub last_name: Option<String>,
+    pub username: Option<String>,
+    pub photo_url: Option<String>,
+    /// unix time when the user was authorized
+    pub auth_date: u64,
+    pub hash: String,
+}
+
+impl LoginData {
+    /// Check the query string of a `LoginUrl` redirect (or the widget `data-auth-url`)
+    /// with the bot token and reject authorizations older than `max_age`
+    pub fn validate(query: &str, bot_token: &str, max_age: Duration) -> Result<Self> {
+        let fields = form_urlencoded::parse(query.as_bytes())
+            .into_owned()
+            .collect::<BTreeMap<_, _>>();
+        check(&fields, bot_token)?;
+
+        let field = |name: &'static str| fields.get(name).cloned();
+        let data = Self {
+            id: field("id")
+                .ok_or(Error::MissingField("id"))?
+                .parse()
+                .map_err(|e: std::num::ParseIntError| Error::InvalidField("id", e.to_string()))?,
+            first_name: field("first_name").ok_or(Error::MissingField("first_name"))?,
+            last_name: field("last_name"),
+            username: field("username"),
+            photo_url: field("photo_url"),
+            auth_date: field("auth_date")
+                .ok_or(Error::MissingField("auth_date"))?
+                .parse()
+                .map_err(|e: std::num::ParseIntError| {
+                    Error::InvalidField("auth_date", e.to_string())
+                })?,
+            hash: field("hash").ok_or(Error::MissingField("hash"))?,
+        };
+        data.check_auth_date(max_age)?;
+        Ok(data)
+    }
+
+    /// Check the object passed to the widget `data-onauth` callback
+    pub fn verify(&self, bot_token: &str, max_age: Duration) -> Result<()> {
+        let mut fields = BTreeMap::from([
+            ("id".to_owned(), self.id.to_string()),
+            ("first_name".to_owned(), self.first_name.clone()),
+            ("auth_date".to_owned(), self.auth_date.to_string()),
+            ("hash".to_owned(), self.hash.clone()),
+        ]);
+        let optional = [
+            ("last_name", &self.last_name),
+            ("username", &self.username),
+            ("photo_url", &self.photo_url),
+        ];
+        for (name, value) in optional {
+            if let Some(value) = value {
+                fields.insert(name.to_owned(), value.clone());
+            }
+        }
+        check(&fields, bot_token)?;
+        self.check_auth_date(max_age)
+    }
+
+    fn check_auth_date(&self, max_age: Duration) -> Result<()> {
+        if signature::is_expired(self.auth_date, max_age) {
+            return Err(Error::Expired);
+        }
+        Ok(())
+    }
+}
+
+/// unlike web apps the secret key is the SHA-256 of the bot token
+fn check(fields: &BTreeMap<String, String>, bot_token: &str) -> Result<()> {
+    let hash = fields.get("hash").ok_or(Error::MissingField("hash"))?;
+    let secret_key = Sha256::digest(bot_token.as_bytes());
+    if !signature::verify(fields, &secret_key, hash) {
+        return Err(Error::InvalidHash);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signature::tests::{now, signed_query, BOT_TOKEN};
+
+    fn sign(fields: &[(&str, String)]) -> String {
+        signature::tests::sign(fields, &Sha256::digest(BOT_TOKEN.as_bytes()))
+    }
+
+    #[test]
+    fn validate_login_url() {
+        let query = signed_query(
+            &[
+                ("id", "279058397".to_owned()),
+                ("first_name", "Vladislav".to_owned()),
+                ("username", "vdkfrost".to_owned()),
+                ("auth_date", now().to_string()),
+            ],
+            &Sha256::digest(BOT_TOKEN.as_bytes()),
+        );
+
+        let data = LoginData::validate(&query, BOT_TOKEN, Duration::from_secs(60)).unwrap();
+        assert_eq!(data.id, 279058397);
+        assert_eq!(data.username.as_deref(), Some("vdkfrost"));
+        assert!(data.verify(BOT_TOKEN, Duration::from_secs(60)).is_ok());
+
+        let tampered = query.replace("279058397", "279058398");
+        assert!(matches!(
+            LoginData::validate(&tampered, BOT_TOKEN, Duration::from_secs(60)),
+            Err(Error::InvalidHash)
+        ));
+    }
+
+    #[test]
+    fn expired_login() {
+        let auth_date = now() - 86400 * 2;
+        let fields = [
+            ("id", "1".to_owned()),
+            ("first_name", "a".to_owned()),
+            ("auth_date", auth_date.to_string()),
+        ];
+        let data = LoginData {
+            id: 1,
+            first_name: "a".to_owned(),
+            last_name: None,
+            username: None,
+            photo_url: None,
+            auth_date,
+            hash: sign(&fields),
+        };
+        assert!(matches!(
+            data.verify(BOT_TOKEN, Duration::from_secs(86400)),
+            Err(Error::Expired)
+        ));
+        assert!(data
+            .verify(BOT_TOKEN, Duration::from_secs(86400 * 3))
+            .is_ok());
+    }
+}
@@ -0,0 +1,260 @@
+use std::{
+    collections::HashMap,
+    mem,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use sha2::{Digest, Sha256};
+
+use crate::{
+    client::{Client, Error},
+    methods::{media::CacheableMethod, SendFile, UploadFile},
+    types::message::Message,
+};
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Maps the method name and content hash of uploaded files to their file_id, `put` may block
+pub trait MediaStore: Send + Sync + 'static {
+    fn get(&self, hash: &str) -> Option<String>;
+
+    fn put(&self, hash: &str, file_id: &str) -> Result<()>;
+
+    /// the file_id was rejected, e.g. it belongs to another bot
+    fn remove(&self, hash: &str) -> Result<()>;
+}
+
+#[derive(Default)]
+pub struct MemoryStore {
+    files: Mutex<HashMap<String, String>>,
+}
+
+impl MediaStore for MemoryStore {
+    fn get(&self, hash: &str) -> Option<String> {
+        self.files.lock().unwrap().get(hash).cloned()
+    }
+
+    fn put(&self, hash: &str, file_id: &str) -> Result<()> {
+        self.files
+            .lock()
+            .unwrap()
+            .insert(hash.to_owned(), file_id.to_owned());
+        Ok(())
+    }
+
+    fn remove(&self, hash: &str) -> Result<()> {
+        self.files.lock().unwrap().remove(hash);
+        Ok(())
+    }
+}
+
+/// Keeps the file_ids in a json file so they survive restarts
+pub struct FileStore {
+    path: PathBuf,
+    files: Mutex<HashMap<String, String>>,
+    /// serializes the writes, `get` is not blocked while the file is written
+    write: Mutex<()>,
+}
+
+impl FileStore {
+    /// Load the file_ids from `path`, the file is created on the first upload
+    pub fn open<P>(path: P) -> Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref().to_owned();
+        let files = match std::fs::read(&path) {
+            Ok(content) => serde_json::from_slice(&content)?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => HashMap::new(),
+            Err(e) => return Err(e.into()),
+        };
+        Ok(Self {
+            path,
+            files: Mutex::new(files),
+            write: Mutex::new(()),
+        })
+    }
+
+    fn save(&self) -> Result<()> {
+        let _write = self.write.lock().unwrap();
+        let content = serde_json::to_vec(&*self.files.lock().unwrap())?;
+        // write then rename, a crash never leaves a truncated store behind
+        let tmp_path = self.path.with_extension("tmp");
+        std::fs::write(&tmp_path, content)?;
+        std::fs::rename(&tmp_path, &self.path)?;
+        Ok(())
+    }
+}
+
+impl MediaStore for FileStore {
+    fn get(&self, hash: &str) -> Option<String> {
+        self.files.lock().unwrap().get(hash).cloned()
+    }
+
+    fn put(&self, hash: &str, file_id: &str) -> Result<()> {
+        self.files
+            .lock()
+            .unwrap()
+            .insert(hash.to_owned(), file_id.to_owned());
+        self.save()
+    }
+
+    fn remove(&self, hash: &str) -> Result<()> {
+        self.files.lock().unwrap().remove(hash);
+        self.save()
+    }
+}
+
+/// Sends media methods, uploads of already sent content are replaced with the cached file_id
+pub struct MediaCache<S> {
+    client: Client,
+    store: Arc<S>,
+}
+
+impl<S> MediaCache<S>
+where
+    S: MediaStore,
+{
+    pub fn new(client: Client, store: S) -> Self {
+        Self {
+            client,
+            store: Arc::new(store),
+        }
+    }
+
+    /// A rejected cached file_id is removed from the store and the file is uploaded again
+    pub async fn send<T>(&self, mut method: T) -> Result<Message>
+    where
+        T: CacheableMethod,
+    {
+        let file = match mem::replace(method.media_mut(), SendFile::id_or_url("")) {
+            SendFile::UploadInput(file) => file,
+            media => {
+                *method.media_mut() = media;
+                return self.client.send_media(method).await;
+            }
+        };
+        // hashing large files would block the runtime
+        let (file, key) = blocking(move || {
+            let key = cache_key::<T>(&file);
+            (file, key)
+        })
+        .await?;
+
+        if let Some(file_id) = self.store.get(&key) {
+            *method.media_mut() = SendFile::id_or_url(file_id);
+            if let Ok(message) = self.client.send_media_json(&method).await {
+                return Ok(message);
+            }
+            let (store, key) = (self.store.clone(), key.clone());
+            blocking(move || store.remove(&key)).await??;
+        }
+
+        *method.media_mut() = SendFile::UploadInput(file);
+        let message: Message = self.client.send_media(method).await?;
+        if let Some(file_id) = T::sent_file_id(&message) {
+            let (store, file_id) = (self.store.clone(), file_id.to_owned());
+            blocking(move || store.put(&key, &file_id)).await??;
+        }
+        Ok(message)
+    }
+}
+
+async fn blocking<F, R>(f: F) -> Result<R>
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    tokio::task::spawn_blocking(f)
+        .await
+        .map_err(|e| Error::Io(e.to_string()))
+}
+
+/// hex encoded SHA-256 of the file content
+pub fn content_hash(file: &UploadFile) -> String {
+    hex::encode(Sha256::digest(file.file_bytes()))
+}
+
+/// file_ids are only valid for the same kind of media, e.g. a photo can't be sent as document
+fn cache_key<T>(file: &UploadFile) -> String
+where
+    T: CacheableMethod,
+{
+    format!("{}:{}", T::method_name(), content_hash(file))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        methods::{
+            media::{SendDocument, SendPhoto, SendPhotoBuilder},
+            sticker::SendSticker,
+            ChatId,
+        },
+        types::message::fixture::message_json,
+    };
+
+    use super::*;
+
+    #[test]
+    fn file_store() {
+        let path = std::env::temp_dir().join("tgbotool_media_cache_file_store.json");
+        let _ = std::fs::remove_file(&path);
+
+        let hash = content_hash(&UploadFile::new("cat.png", b"cat".to_vec()));
+        assert_eq!(
+            hash,
+            content_hash(&UploadFile::new("other.png", b"cat".to_vec()))
+        );
+
+        let store = FileStore::open(&path).unwrap();
+        assert!(store.get(&hash).is_none());
+        store.put(&hash, "file-id").unwrap();
+
+        let store = FileStore::open(&path).unwrap();
+        assert_eq!(store.get(&hash).as_deref(), Some("file-id"));
+        store.remove(&hash).unwrap();
+        assert!(FileStore::open(&path).unwrap().get(&hash).is_none());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn cache_key_per_method() {
+        let file = UploadFile::new("cat.png", b"cat".to_vec());
+        let photo = cache_key::<SendPhoto>(&file);
+        assert_eq!(photo, format!("sendPhoto:{}", content_hash(&file)));
+        assert_ne!(photo, cache_key::<SendDocument>(&file));
+        assert_ne!(photo, cache_key::<SendSticker>(&file));
+    }
+
+    #[tokio::test]
+    async fn evict_rejected_file_id() {
+        let file = UploadFile::new("cat.png", b"cat".to_vec());
+        let key = cache_key::<SendPhoto>(&file);
+        let store = MemoryStore::default();
+        store.put(&key, "other-bot-file-id").unwrap();
+
+        // the invalid token makes every request fail
+        let cache = MediaCache::new(Client::new("token", reqwest::Client::new()), store);
+        let photo = SendPhotoBuilder::new(ChatId::Chat(7), SendFile::UploadInput(file)).build();
+        assert!(cache.send(photo).await.is_err());
+        assert!(cache.store.get(&key).is_none());
+    }
+
+    #[test]
+    fn sent_photo_file_id() {
+        let message: Message = serde_json::from_value(message_json(
+            1,
+            serde_json::json!({
+                "photo": [
+                    {"file_id": "small", "file_unique_id": "s", "width": 90, "height": 90},
+                    {"file_id": "large", "file_unique_id": "l", "width": 1280, "height": 1280},
+                    {"file_id": "medium", "file_unique_id": "m", "width": 320, "height": 320}
+                ]
+            }),
+        ))
+        .unwrap();
+        assert_eq!(SendPhoto::sent_file_id(&message), Some("large"));
+    }
+}
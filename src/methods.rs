@@ -1,39 +1,178 @@
+use std::{
+    mem,
+    path::{Path, PathBuf},
+};
+
 use reqwest::multipart;
+use tokio::io::AsyncRead;
+use tokio_util::io::ReaderStream;
 
-use crate::types::{
-    ForceReply, InlineKeyboardButton, InlineKeyboardMarkup, ReplyKeyboardMarkup,
-    ReplyKeyboardRemove,
+use crate::{
+    client::Error,
+    types::{
+        message::{Message, MessageMedia},
+        CallbackQuery, ForceReply, InlineKeyboardButton, InlineKeyboardMarkup, ReplyKeyboardMarkup,
+        ReplyKeyboardRemove,
+    },
 };
 
 pub mod answer_callback_query;
+pub mod bot;
+pub mod chat;
+pub mod chat_action;
+pub mod contact;
+pub mod dice;
+pub mod forum_topic;
+pub mod game;
 pub mod get_file;
 pub mod get_updates;
+pub mod inline_query;
+pub mod location;
 pub mod media;
 pub mod media_group;
 pub mod message;
+pub mod passport;
+pub mod payment;
 pub mod poll;
+pub mod sticker;
+pub mod user_profile_photos;
+pub mod web_app;
 
 pub trait TgMethod: serde::Serialize {
     fn method_name() -> String;
 }
 
 pub trait TgMultipartMethod:
-    serde::Serialize + TryInto<reqwest::multipart::Form, Error = serde_json::Error>
+    serde::Serialize + TryInto<reqwest::multipart::Form, Error = Error> + Send + 'static
 {
     fn method_name() -> String;
 
     fn is_multipart(&self) -> bool {
         false
     }
+
+    /// checked before the request is sent
+    fn validate(&self) -> Result<(), Error> {
+        Ok(())
+    }
 }
 
-#[derive(serde::Serialize)]
+/// Input objects sending their files as `attach://<file_attach_name>`, see `#[multipart(input)]`
+pub(crate) trait AttachInput {
+    fn is_multipart(&self) -> bool;
+
+    /// replace the uploaded files with `attach://` and return their parts
+    fn take_file_parts(
+        &mut self,
+        names: &mut AttachNames,
+    ) -> Result<Vec<(String, multipart::Part)>, Error>;
+}
+
+/// Unique attach names within a form, file names may be duplicated
+#[derive(Default)]
+pub(crate) struct AttachNames {
+    next: usize,
+}
+
+impl AttachNames {
+    fn next_name(&mut self) -> String {
+        let name = format!("file{}", self.next);
+        self.next += 1;
+        name
+    }
+}
+
+impl<T: AttachInput> AttachInput for Vec<T> {
+    fn is_multipart(&self) -> bool {
+        self.iter().any(T::is_multipart)
+    }
+
+    fn take_file_parts(
+        &mut self,
+        names: &mut AttachNames,
+    ) -> Result<Vec<(String, multipart::Part)>, Error> {
+        let mut parts = Vec::new();
+        for input in self {
+            parts.extend(input.take_file_parts(names)?);
+        }
+        Ok(parts)
+    }
+}
+
+/// form field value, strings are sent without json quotes
+pub(crate) fn form_value<T>(value: &T) -> Result<String, serde_json::Error>
+where
+    T: serde::Serialize,
+{
+    match serde_json::to_value(value)? {
+        serde_json::Value::String(s) => Ok(s),
+        value => Ok(value.to_string()),
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
 #[serde(untagged)]
 pub enum ChatId {
     Chat(i64),
     Channel(String),
 }
 
+/// The message to edit: a message sent by the bot in a chat, or a message sent via the bot in inline mode.
+///
+/// Editing methods return the edited `Message` for `Chat` targets and `true` for `Inline` targets.
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(untagged)]
+pub enum MessageTarget {
+    Chat { chat_id: ChatId, message_id: u64 },
+    Inline { inline_message_id: String },
+}
+
+impl MessageTarget {
+    pub fn chat(chat_id: ChatId, message_id: u64) -> Self {
+        Self::Chat {
+            chat_id,
+            message_id,
+        }
+    }
+
+    pub fn inline(inline_message_id: &str) -> Self {
+        Self::Inline {
+            inline_message_id: inline_message_id.to_owned(),
+        }
+    }
+
+    /// the message the callback button was attached to
+    ///
+    /// returns `None` if the message is too old to be delivered with the callback query
+    pub fn from_callback_query(callback_query: &CallbackQuery) -> Option<Self> {
+        match (&callback_query.message, &callback_query.inline_message_id) {
+            (Some(message), _) => Some(message.into()),
+            (None, Some(inline_message_id)) => Some(Self::inline(inline_message_id)),
+            (None, None) => None,
+        }
+    }
+
+    /// used by multipart form, the target is flattened into the top-level fields
+    pub(crate) fn append_to_form(
+        self,
+        mut form: reqwest::multipart::Form,
+    ) -> Result<reqwest::multipart::Form, Error> {
+        match self {
+            MessageTarget::Chat {
+                chat_id,
+                message_id,
+            } => {
+                form = form.text("chat_id", form_value(&chat_id)?);
+                form = form.text("message_id", message_id.to_string());
+            }
+            MessageTarget::Inline { inline_message_id } => {
+                form = form.text("inline_message_id", inline_message_id);
+            }
+        }
+        Ok(form)
+    }
+}
+
 #[derive(serde::Serialize, serde::Deserialize)]
 #[serde(untagged)]
 pub enum ReplyMarkup {
@@ -49,13 +188,35 @@ impl ReplyMarkup {
     }
 }
 
-#[derive(serde::Serialize)]
-#[serde(untagged)]
+#[derive(Debug, thiserror::Error)]
+pub enum SendFileError {
+    #[error("{0} can only be uploaded, not sent by file_id or url")]
+    UploadRequired(&'static str),
+}
+
 pub enum SendFile {
     UploadInput(UploadFile),
+    /// file on the local filesystem, opened when the request is sent
+    Path(PathBuf),
+    UploadStream(UploadStream),
     FileIdOrUrl(String),
 }
 
+/// only file_id / url can be serialized, uploads are sent as multipart
+impl serde::Serialize for SendFile {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            SendFile::FileIdOrUrl(s) => serializer.serialize_str(s),
+            _ => Err(serde::ser::Error::custom(
+                "uploaded file must be sent as multipart",
+            )),
+        }
+    }
+}
+
 #[derive(serde::Serialize)]
 pub struct UploadFile {
     file_name: String,
@@ -69,6 +230,29 @@ impl UploadFile {
             file_bytes,
         }
     }
+    pub fn file_bytes(&self) -> &[u8] {
+        &self.file_bytes
+    }
+}
+
+/// Streamed upload, the length must be known in advance
+pub struct UploadStream {
+    file_name: String,
+    length: u64,
+    reader: Box<dyn AsyncRead + Send + Sync + Unpin>,
+}
+
+impl UploadStream {
+    pub fn new<R>(file_name: &str, length: u64, reader: R) -> Self
+    where
+        R: AsyncRead + Send + Sync + Unpin + 'static,
+    {
+        Self {
+            file_name: file_name.to_owned(),
+            length,
+            reader: Box::new(reader),
+        }
+    }
 }
 
 pub enum FilePart {
@@ -90,40 +274,162 @@ impl SendFile {
             file_bytes: input,
         })
     }
-}
 
-/// used by normal multipart
-impl From<SendFile> for reqwest::multipart::Part {
-    fn from(this: SendFile) -> Self {
-        match this {
+    pub fn path<P>(path: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        Self::Path(path.into())
+    }
+
+    pub fn stream<R>(file_name: &str, length: u64, reader: R) -> Self
+    where
+        R: AsyncRead + Send + Sync + Unpin + 'static,
+    {
+        Self::UploadStream(UploadStream::new(file_name, length, reader))
+    }
+
+    pub fn is_upload(&self) -> bool {
+        !matches!(self, SendFile::FileIdOrUrl(_))
+    }
+
+    /// used by normal multipart
+    pub(crate) fn into_part(self) -> Result<multipart::Part, Error> {
+        let part = match self {
             SendFile::UploadInput(UploadFile {
                 file_name,
                 file_bytes,
             }) => multipart::Part::bytes(file_bytes).file_name(file_name),
-            SendFile::FileIdOrUrl(s) => reqwest::multipart::Part::text(s),
+            SendFile::Path(path) => path_part(&path)?,
+            SendFile::UploadStream(UploadStream {
+                file_name,
+                length,
+                reader,
+            }) => {
+                let body = reqwest::Body::wrap_stream(ReaderStream::new(reader));
+                multipart::Part::stream_with_length(body, length).file_name(file_name)
+            }
+            SendFile::FileIdOrUrl(s) => multipart::Part::text(s),
+        };
+        Ok(part)
+    }
+
+    /// used by attach multipart
+    pub(crate) fn into_attach_parts(
+        mut self,
+        names: &mut AttachNames,
+    ) -> Result<Vec<FilePart>, Error> {
+        let file_part = self.take_attach_part(names)?;
+        let mut parts = vec![FilePart::Simple(self.into_part()?)];
+        parts.extend(file_part.map(FilePart::Complex));
+        Ok(parts)
+    }
+
+    /// used by input objects, the upload is replaced with `attach://<attach_name>`
+    pub(crate) fn take_attach_part(
+        &mut self,
+        names: &mut AttachNames,
+    ) -> Result<Option<(String, multipart::Part)>, Error> {
+        if !self.is_upload() {
+            return Ok(None);
         }
+        let attach_name = names.next_name();
+        let attach = SendFile::FileIdOrUrl(format!("attach://{attach_name}"));
+        let part = mem::replace(self, attach).into_part()?;
+        Ok(Some((attach_name, part)))
     }
 }
 
-/// used by attach multipart
-impl From<SendFile> for Vec<FilePart> {
-    fn from(this: SendFile) -> Self {
-        let mut res = Vec::new();
-        match this {
-            SendFile::UploadInput(UploadFile {
-                file_name,
-                file_bytes,
-            }) => {
-                res.push(FilePart::Simple(multipart::Part::text(format!(
-                    "attach://{file_name}"
-                ))));
-                res.push(FilePart::Complex((
-                    file_name.to_owned(),
-                    multipart::Part::bytes(file_bytes).file_name(file_name.clone()),
-                )))
-            }
-            SendFile::FileIdOrUrl(s) => res.push(FilePart::Simple(multipart::Part::text(s))),
+fn path_file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "file".to_owned())
+}
+
+fn path_part(path: &Path) -> Result<multipart::Part, Error> {
+    let file = std::fs::File::open(path)?;
+    let length = file.metadata()?.len();
+    let body = reqwest::Body::wrap_stream(ReaderStream::new(tokio::fs::File::from_std(file)));
+    let mime = mime_guess::from_path(path).first_or_octet_stream();
+    let part = multipart::Part::stream_with_length(body, length)
+        .file_name(path_file_name(path))
+        .mime_str(mime.as_ref())?;
+    Ok(part)
+}
+
+/// resend the media by file_id
+impl From<MessageMedia<'_>> for SendFile {
+    fn from(media: MessageMedia<'_>) -> Self {
+        Self::id_or_url(media.file_id())
+    }
+}
+
+impl From<&Message> for MessageTarget {
+    fn from(message: &Message) -> Self {
+        Self::chat(ChatId::Chat(message.chat_id()), message.message_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{media::SendDocumentBuilder, *};
+
+    #[test]
+    fn chat_id_form_value() {
+        assert_eq!(
+            form_value(&ChatId::Channel("@chan".to_owned())).unwrap(),
+            "@chan"
+        );
+        assert_eq!(form_value(&ChatId::Chat(-100)).unwrap(), "-100");
+    }
+
+    #[test]
+    fn attach_parts_share_names() {
+        let mut names = AttachNames::default();
+        for expected in ["file0", "file1"] {
+            let parts = SendFile::upload("thumb.jpg", b"thumb".to_vec())
+                .into_attach_parts(&mut names)
+                .unwrap();
+            let [FilePart::Simple(_), FilePart::Complex((name, _))] = &parts[..] else {
+                panic!("expected the attach reference and the file")
+            };
+            assert_eq!(name, expected);
         }
-        res
+    }
+
+    #[test]
+    fn path_upload_form() {
+        let path = std::env::temp_dir().join("tgbotool_path_upload_form.txt");
+        std::fs::write(&path, b"txt").unwrap();
+
+        let method = SendDocumentBuilder::new(ChatId::Chat(1), SendFile::path(&path))
+            .caption("doc")
+            .build();
+        assert!(method.is_multipart());
+        assert!(multipart::Form::try_from(method).is_ok());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn attach_path_upload() {
+        let path = std::env::temp_dir().join("tgbotool_attach_path_upload.png");
+        std::fs::write(&path, b"png").unwrap();
+
+        let mut file = SendFile::path(&path);
+        assert!(file.is_upload());
+        assert!(serde_json::to_string(&file).is_err());
+
+        let mut names = AttachNames::default();
+        let (attach_name, _) = file.take_attach_part(&mut names).unwrap().unwrap();
+        assert_eq!(attach_name, "file0");
+        assert_eq!(serde_json::to_string(&file).unwrap(), r#""attach://file0""#);
+        assert!(file.take_attach_part(&mut names).unwrap().is_none());
+
+        std::fs::remove_file(&path).unwrap();
+        assert!(matches!(
+            SendFile::path(&path).into_part(),
+            Err(Error::Io(_))
+        ));
     }
 }
@@ -9,3 +9,12 @@ pub struct AnswerCallbackQuery {
     url: Option<String>,
     cache_time: Option<u64>,
 }
+
+impl AnswerCallbackQuery {
+    /// open the game of a `callback_game` button, the url is opened in the user's in-app browser
+    pub fn url(callback_query_id: &str, url: &str) -> Self {
+        AnswerCallbackQueryBuilder::new(callback_query_id)
+            .url(url)
+            .build()
+    }
+}
@@ -0,0 +1,132 @@
+use serde_with::skip_serializing_none;
+
+use crate::types::{BotCommand, ChatAdministratorRights, MenuButton};
+
+use super::ChatId;
+
+/// returns the bot `User`
+#[derive(Default, serde::Serialize, tgbotool_derive::TgMethod)]
+pub struct GetMe {}
+
+/// log out from the cloud Bot API server before launching the bot locally
+#[derive(Default, serde::Serialize, tgbotool_derive::TgMethod)]
+pub struct LogOut {}
+
+/// close the bot instance before moving it from one local server to another
+#[derive(Default, serde::Serialize, tgbotool_derive::TgMethod)]
+pub struct Close {}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct SetMyName {
+    /// `None` removes the dedicated name for the language
+    name: Option<String>,
+    /// `None` applies to all users without a dedicated name
+    language_code: Option<String>,
+}
+
+/// returns `BotName`
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct GetMyName {
+    language_code: Option<String>,
+}
+
+/// shown in the chat with the bot if the chat is empty
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct SetMyDescription {
+    description: Option<String>,
+    language_code: Option<String>,
+}
+
+/// returns `BotDescription`
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct GetMyDescription {
+    language_code: Option<String>,
+}
+
+/// shown on the bot's profile page and sent together with the link when users share the bot
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct SetMyShortDescription {
+    short_description: Option<String>,
+    language_code: Option<String>,
+}
+
+/// returns `BotShortDescription`
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct GetMyShortDescription {
+    language_code: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct SetChatMenuButton {
+    /// `None` changes the default menu button
+    chat_id: Option<i64>,
+    /// `None` resets to `MenuButton::Default`
+    menu_button: Option<MenuButton>,
+}
+
+/// returns `MenuButton`
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct GetChatMenuButton {
+    chat_id: Option<i64>,
+}
+
+/// suggested to users when the bot is added as an administrator
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct SetMyDefaultAdministratorRights {
+    /// `None` clears the default rights
+    rights: Option<ChatAdministratorRights>,
+    /// rights in channels instead of groups
+    for_channels: Option<bool>,
+}
+
+/// returns `ChatAdministratorRights`
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct GetMyDefaultAdministratorRights {
+    for_channels: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct SetMyCommands {
+    commands: Vec<BotCommand>,
+    scope: Option<BotCommandScope>,
+    language_code: Option<String>,
+}
+
+/// returns `Vec<BotCommand>`, empty if the commands are not set
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct GetMyCommands {
+    scope: Option<BotCommandScope>,
+    language_code: Option<String>,
+}
+
+/// the commands of lower level scopes will be shown to the users
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct DeleteMyCommands {
+    scope: Option<BotCommandScope>,
+    language_code: Option<String>,
+}
+
+#[derive(serde::Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum BotCommandScope {
+    Default,
+    AllPrivateChats,
+    AllGroupChats,
+    AllChatAdministrators,
+    Chat { chat_id: ChatId },
+    ChatAdministrators { chat_id: ChatId },
+    ChatMember { chat_id: ChatId, user_id: u64 },
+}
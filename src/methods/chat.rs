@@ -0,0 +1,93 @@
+use serde_with::skip_serializing_none;
+
+use super::{ChatId, SendFile, SendFileError};
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct PinChatMessage {
+    chat_id: ChatId,
+    message_id: u64,
+    disable_notification: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct UnpinChatMessage {
+    chat_id: ChatId,
+    /// the most recent pinned message will be unpinned if not specified
+    message_id: Option<u64>,
+}
+
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct UnpinAllChatMessages {
+    chat_id: ChatId,
+}
+
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct SetChatTitle {
+    chat_id: ChatId,
+    title: String,
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct SetChatDescription {
+    chat_id: ChatId,
+    description: Option<String>,
+}
+
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::Multipart)]
+#[multipart(validate = "validate_photo")]
+pub struct SetChatPhoto {
+    chat_id: ChatId,
+    /// photos can't be set by file_id / url, only uploading is supported
+    #[multipart(normal)]
+    photo: SendFile,
+}
+
+impl SetChatPhoto {
+    pub fn validate_photo(&self) -> Result<(), SendFileError> {
+        if !self.photo.is_upload() {
+            return Err(SendFileError::UploadRequired("chat photo"));
+        }
+        Ok(())
+    }
+}
+
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct DeleteChatPhoto {
+    chat_id: ChatId,
+}
+
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct SetChatStickerSet {
+    chat_id: ChatId,
+    sticker_set_name: String,
+}
+
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct DeleteChatStickerSet {
+    chat_id: ChatId,
+}
+
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct LeaveChat {
+    chat_id: ChatId,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chat_photo_upload_only() {
+        let photo = |photo| SetChatPhotoBuilder::new(ChatId::Chat(7), photo).build();
+        assert!(matches!(
+            photo(SendFile::id_or_url("file-id")).validate_photo(),
+            Err(SendFileError::UploadRequired(_))
+        ));
+        assert!(photo(SendFile::upload("chat.jpg", b"jpg".to_vec()))
+            .validate_photo()
+            .is_ok());
+    }
+}
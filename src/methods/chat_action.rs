@@ -0,0 +1,27 @@
+use serde_with::skip_serializing_none;
+
+use super::ChatId;
+
+#[skip_serializing_none]
+#[derive(Clone, serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct SendChatAction {
+    chat_id: ChatId,
+    message_thread_id: Option<u64>,
+    action: ChatAction,
+}
+
+#[derive(Debug, Clone, Copy, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChatAction {
+    Typing,
+    UploadPhoto,
+    RecordVideo,
+    UploadVideo,
+    RecordVoice,
+    UploadVoice,
+    UploadDocument,
+    ChooseSticker,
+    FindLocation,
+    RecordVideoNote,
+    UploadVideoNote,
+}
@@ -0,0 +1,20 @@
+use serde_with::skip_serializing_none;
+
+use super::{ChatId, ReplyMarkup};
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct SendContact {
+    chat_id: ChatId,
+    message_thread_id: Option<u64>,
+    phone_number: String,
+    first_name: String,
+    last_name: Option<String>,
+    /// additional data about the contact in the form of a vCard, 0-2048 bytes
+    vcard: Option<String>,
+    disable_notification: Option<bool>,
+    protect_content: Option<bool>,
+    reply_to_message_id: Option<u64>,
+    allow_sending_without_reply: Option<bool>,
+    reply_markup: Option<ReplyMarkup>,
+}
@@ -0,0 +1,38 @@
+use serde_with::skip_serializing_none;
+
+use super::{ChatId, ReplyMarkup};
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct SendDice {
+    chat_id: ChatId,
+    message_thread_id: Option<u64>,
+    emoji: Option<DiceEmoji>,
+    disable_notification: Option<bool>,
+    protect_content: Option<bool>,
+    reply_to_message_id: Option<u64>,
+    allow_sending_without_reply: Option<bool>,
+    reply_markup: Option<ReplyMarkup>,
+}
+
+#[derive(Debug, Clone, Copy, serde::Serialize)]
+pub enum DiceEmoji {
+    /// 1-6
+    #[serde(rename = "🎲")]
+    Dice,
+    /// 1-6
+    #[serde(rename = "🎯")]
+    Darts,
+    /// 1-6
+    #[serde(rename = "🎳")]
+    Bowling,
+    /// 1-5
+    #[serde(rename = "🏀")]
+    Basketball,
+    /// 1-5
+    #[serde(rename = "⚽")]
+    Football,
+    /// 1-64
+    #[serde(rename = "🎰")]
+    SlotMachine,
+}
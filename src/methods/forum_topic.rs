@@ -0,0 +1,80 @@
+use serde_with::skip_serializing_none;
+
+use super::ChatId;
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct CreateForumTopic {
+    chat_id: ChatId,
+    name: String,
+    icon_color: Option<usize>,
+    icon_custom_emoji_id: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct EditForumTopic {
+    chat_id: ChatId,
+    message_thread_id: u64,
+    name: Option<String>,
+    icon_custom_emoji_id: Option<String>,
+}
+
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct CloseForumTopic {
+    chat_id: ChatId,
+    message_thread_id: u64,
+}
+
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct ReopenForumTopic {
+    chat_id: ChatId,
+    message_thread_id: u64,
+}
+
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct DeleteForumTopic {
+    chat_id: ChatId,
+    message_thread_id: u64,
+}
+
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct UnpinAllForumTopicMessages {
+    chat_id: ChatId,
+    message_thread_id: u64,
+}
+
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct EditGeneralForumTopic {
+    chat_id: ChatId,
+    name: String,
+}
+
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct CloseGeneralForumTopic {
+    chat_id: ChatId,
+}
+
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct ReopenGeneralForumTopic {
+    chat_id: ChatId,
+}
+
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct HideGeneralForumTopic {
+    chat_id: ChatId,
+}
+
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct UnhideGeneralForumTopic {
+    chat_id: ChatId,
+}
+
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct UnpinAllGeneralForumTopicMessages {
+    chat_id: ChatId,
+}
+
+/// returns `Vec<Sticker>` which can be used as a forum topic icon
+#[derive(Default, serde::Serialize, tgbotool_derive::TgMethod)]
+pub struct GetForumTopicIconStickers {}
@@ -0,0 +1,39 @@
+use serde_with::skip_serializing_none;
+
+use crate::types::InlineKeyboardMarkup;
+
+use super::MessageTarget;
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct SendGame {
+    chat_id: i64,
+    message_thread_id: Option<u64>,
+    game_short_name: String,
+    disable_notification: Option<bool>,
+    protect_content: Option<bool>,
+    reply_to_message_id: Option<u64>,
+    allow_sending_without_reply: Option<bool>,
+    /// the first button must launch the game
+    reply_markup: Option<InlineKeyboardMarkup>,
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct SetGameScore {
+    user_id: u64,
+    score: u64,
+    /// allow the score to decrease
+    force: Option<bool>,
+    disable_edit_message: Option<bool>,
+    #[serde(flatten)]
+    target: MessageTarget,
+}
+
+/// returns `Vec<GameHighScore>` of the user and several of their neighbors
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct GetGameHighScores {
+    user_id: u64,
+    #[serde(flatten)]
+    target: MessageTarget,
+}
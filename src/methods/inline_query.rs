@@ -0,0 +1,530 @@
+use serde_with::skip_serializing_none;
+
+use crate::types::{
+    message::MessageEntity, payment::LabeledPrice, InlineKeyboardMarkup, WebAppInfo,
+};
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct AnswerInlineQuery {
+    inline_query_id: String,
+    /// no more than 50 results per query are allowed
+    results: Vec<InlineQueryResult>,
+    cache_time: Option<u64>,
+    is_personal: Option<bool>,
+    /// pass an empty string if there are no more results, no more than 64 bytes
+    next_offset: Option<String>,
+    button: Option<InlineQueryResultsButton>,
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder)]
+pub struct InlineQueryResultsButton {
+    text: String,
+    web_app: Option<WebAppInfo>,
+    start_parameter: Option<String>,
+}
+
+#[derive(serde::Serialize)]
+#[serde(untagged)]
+pub enum InlineQueryResult {
+    Article(InlineQueryResultArticle),
+    Photo(InlineQueryResultPhoto),
+    Gif(InlineQueryResultGif),
+    Mpeg4Gif(InlineQueryResultMpeg4Gif),
+    Video(InlineQueryResultVideo),
+    Audio(InlineQueryResultAudio),
+    Voice(InlineQueryResultVoice),
+    Document(InlineQueryResultDocument),
+    Location(InlineQueryResultLocation),
+    Venue(InlineQueryResultVenue),
+    Contact(InlineQueryResultContact),
+    Game(InlineQueryResultGame),
+    CachedPhoto(InlineQueryResultCachedPhoto),
+    CachedGif(InlineQueryResultCachedGif),
+    CachedMpeg4Gif(InlineQueryResultCachedMpeg4Gif),
+    CachedSticker(InlineQueryResultCachedSticker),
+    CachedDocument(InlineQueryResultCachedDocument),
+    CachedVideo(InlineQueryResultCachedVideo),
+    CachedVoice(InlineQueryResultCachedVoice),
+    CachedAudio(InlineQueryResultCachedAudio),
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder)]
+pub struct InlineQueryResultArticle {
+    #[serde(rename = "type")]
+    #[builder(value = "article")]
+    result_type: String,
+    id: String,
+    title: String,
+    input_message_content: InputMessageContent,
+    reply_markup: Option<InlineKeyboardMarkup>,
+    url: Option<String>,
+    hide_url: Option<bool>,
+    description: Option<String>,
+    thumbnail_url: Option<String>,
+    thumbnail_width: Option<usize>,
+    thumbnail_height: Option<usize>,
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder)]
+pub struct InlineQueryResultPhoto {
+    #[serde(rename = "type")]
+    #[builder(value = "photo")]
+    result_type: String,
+    id: String,
+    /// jpeg only, must not exceed 5MB
+    photo_url: String,
+    thumbnail_url: String,
+    photo_width: Option<usize>,
+    photo_height: Option<usize>,
+    title: Option<String>,
+    description: Option<String>,
+    caption: Option<String>,
+    parse_mode: Option<String>,
+    caption_entities: Option<Vec<MessageEntity>>,
+    reply_markup: Option<InlineKeyboardMarkup>,
+    input_message_content: Option<InputMessageContent>,
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder)]
+pub struct InlineQueryResultGif {
+    #[serde(rename = "type")]
+    #[builder(value = "gif")]
+    result_type: String,
+    id: String,
+    gif_url: String,
+    gif_width: Option<usize>,
+    gif_height: Option<usize>,
+    gif_duration: Option<usize>,
+    thumbnail_url: String,
+    thumbnail_mime_type: Option<String>,
+    title: Option<String>,
+    caption: Option<String>,
+    parse_mode: Option<String>,
+    caption_entities: Option<Vec<MessageEntity>>,
+    reply_markup: Option<InlineKeyboardMarkup>,
+    input_message_content: Option<InputMessageContent>,
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder)]
+pub struct InlineQueryResultMpeg4Gif {
+    #[serde(rename = "type")]
+    #[builder(value = "mpeg4_gif")]
+    result_type: String,
+    id: String,
+    mpeg4_url: String,
+    mpeg4_width: Option<usize>,
+    mpeg4_height: Option<usize>,
+    mpeg4_duration: Option<usize>,
+    thumbnail_url: String,
+    thumbnail_mime_type: Option<String>,
+    title: Option<String>,
+    caption: Option<String>,
+    parse_mode: Option<String>,
+    caption_entities: Option<Vec<MessageEntity>>,
+    reply_markup: Option<InlineKeyboardMarkup>,
+    input_message_content: Option<InputMessageContent>,
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder)]
+pub struct InlineQueryResultVideo {
+    #[serde(rename = "type")]
+    #[builder(value = "video")]
+    result_type: String,
+    id: String,
+    video_url: String,
+    /// "text/html" or "video/mp4"
+    mime_type: String,
+    thumbnail_url: String,
+    title: String,
+    caption: Option<String>,
+    parse_mode: Option<String>,
+    caption_entities: Option<Vec<MessageEntity>>,
+    video_width: Option<usize>,
+    video_height: Option<usize>,
+    video_duration: Option<usize>,
+    description: Option<String>,
+    reply_markup: Option<InlineKeyboardMarkup>,
+    /// required if the result is a page embedding a video player
+    input_message_content: Option<InputMessageContent>,
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder)]
+pub struct InlineQueryResultAudio {
+    #[serde(rename = "type")]
+    #[builder(value = "audio")]
+    result_type: String,
+    id: String,
+    audio_url: String,
+    title: String,
+    caption: Option<String>,
+    parse_mode: Option<String>,
+    caption_entities: Option<Vec<MessageEntity>>,
+    performer: Option<String>,
+    audio_duration: Option<usize>,
+    reply_markup: Option<InlineKeyboardMarkup>,
+    input_message_content: Option<InputMessageContent>,
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder)]
+pub struct InlineQueryResultVoice {
+    #[serde(rename = "type")]
+    #[builder(value = "voice")]
+    result_type: String,
+    id: String,
+    voice_url: String,
+    title: String,
+    caption: Option<String>,
+    parse_mode: Option<String>,
+    caption_entities: Option<Vec<MessageEntity>>,
+    voice_duration: Option<usize>,
+    reply_markup: Option<InlineKeyboardMarkup>,
+    input_message_content: Option<InputMessageContent>,
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder)]
+pub struct InlineQueryResultDocument {
+    #[serde(rename = "type")]
+    #[builder(value = "document")]
+    result_type: String,
+    id: String,
+    title: String,
+    caption: Option<String>,
+    parse_mode: Option<String>,
+    caption_entities: Option<Vec<MessageEntity>>,
+    document_url: String,
+    /// "application/pdf" or "application/zip"
+    mime_type: String,
+    description: Option<String>,
+    reply_markup: Option<InlineKeyboardMarkup>,
+    input_message_content: Option<InputMessageContent>,
+    thumbnail_url: Option<String>,
+    thumbnail_width: Option<usize>,
+    thumbnail_height: Option<usize>,
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder)]
+pub struct InlineQueryResultLocation {
+    #[serde(rename = "type")]
+    #[builder(value = "location")]
+    result_type: String,
+    id: String,
+    latitude: f64,
+    longitude: f64,
+    title: String,
+    horizontal_accuracy: Option<f64>,
+    live_period: Option<u32>,
+    heading: Option<u16>,
+    proximity_alert_radius: Option<u32>,
+    reply_markup: Option<InlineKeyboardMarkup>,
+    input_message_content: Option<InputMessageContent>,
+    thumbnail_url: Option<String>,
+    thumbnail_width: Option<usize>,
+    thumbnail_height: Option<usize>,
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder)]
+pub struct InlineQueryResultVenue {
+    #[serde(rename = "type")]
+    #[builder(value = "venue")]
+    result_type: String,
+    id: String,
+    latitude: f64,
+    longitude: f64,
+    title: String,
+    address: String,
+    foursquare_id: Option<String>,
+    foursquare_type: Option<String>,
+    google_place_id: Option<String>,
+    google_place_type: Option<String>,
+    reply_markup: Option<InlineKeyboardMarkup>,
+    input_message_content: Option<InputMessageContent>,
+    thumbnail_url: Option<String>,
+    thumbnail_width: Option<usize>,
+    thumbnail_height: Option<usize>,
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder)]
+pub struct InlineQueryResultContact {
+    #[serde(rename = "type")]
+    #[builder(value = "contact")]
+    result_type: String,
+    id: String,
+    phone_number: String,
+    first_name: String,
+    last_name: Option<String>,
+    vcard: Option<String>,
+    reply_markup: Option<InlineKeyboardMarkup>,
+    input_message_content: Option<InputMessageContent>,
+    thumbnail_url: Option<String>,
+    thumbnail_width: Option<usize>,
+    thumbnail_height: Option<usize>,
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder)]
+pub struct InlineQueryResultGame {
+    #[serde(rename = "type")]
+    #[builder(value = "game")]
+    result_type: String,
+    id: String,
+    game_short_name: String,
+    reply_markup: Option<InlineKeyboardMarkup>,
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder)]
+pub struct InlineQueryResultCachedPhoto {
+    #[serde(rename = "type")]
+    #[builder(value = "photo")]
+    result_type: String,
+    id: String,
+    photo_file_id: String,
+    title: Option<String>,
+    description: Option<String>,
+    caption: Option<String>,
+    parse_mode: Option<String>,
+    caption_entities: Option<Vec<MessageEntity>>,
+    reply_markup: Option<InlineKeyboardMarkup>,
+    input_message_content: Option<InputMessageContent>,
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder)]
+pub struct InlineQueryResultCachedGif {
+    #[serde(rename = "type")]
+    #[builder(value = "gif")]
+    result_type: String,
+    id: String,
+    gif_file_id: String,
+    title: Option<String>,
+    caption: Option<String>,
+    parse_mode: Option<String>,
+    caption_entities: Option<Vec<MessageEntity>>,
+    reply_markup: Option<InlineKeyboardMarkup>,
+    input_message_content: Option<InputMessageContent>,
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder)]
+pub struct InlineQueryResultCachedMpeg4Gif {
+    #[serde(rename = "type")]
+    #[builder(value = "mpeg4_gif")]
+    result_type: String,
+    id: String,
+    mpeg4_file_id: String,
+    title: Option<String>,
+    caption: Option<String>,
+    parse_mode: Option<String>,
+    caption_entities: Option<Vec<MessageEntity>>,
+    reply_markup: Option<InlineKeyboardMarkup>,
+    input_message_content: Option<InputMessageContent>,
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder)]
+pub struct InlineQueryResultCachedSticker {
+    #[serde(rename = "type")]
+    #[builder(value = "sticker")]
+    result_type: String,
+    id: String,
+    sticker_file_id: String,
+    reply_markup: Option<InlineKeyboardMarkup>,
+    input_message_content: Option<InputMessageContent>,
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder)]
+pub struct InlineQueryResultCachedDocument {
+    #[serde(rename = "type")]
+    #[builder(value = "document")]
+    result_type: String,
+    id: String,
+    title: String,
+    document_file_id: String,
+    description: Option<String>,
+    caption: Option<String>,
+    parse_mode: Option<String>,
+    caption_entities: Option<Vec<MessageEntity>>,
+    reply_markup: Option<InlineKeyboardMarkup>,
+    input_message_content: Option<InputMessageContent>,
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder)]
+pub struct InlineQueryResultCachedVideo {
+    #[serde(rename = "type")]
+    #[builder(value = "video")]
+    result_type: String,
+    id: String,
+    video_file_id: String,
+    title: String,
+    description: Option<String>,
+    caption: Option<String>,
+    parse_mode: Option<String>,
+    caption_entities: Option<Vec<MessageEntity>>,
+    reply_markup: Option<InlineKeyboardMarkup>,
+    input_message_content: Option<InputMessageContent>,
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder)]
+pub struct InlineQueryResultCachedVoice {
+    #[serde(rename = "type")]
+    #[builder(value = "voice")]
+    result_type: String,
+    id: String,
+    voice_file_id: String,
+    title: String,
+    caption: Option<String>,
+    parse_mode: Option<String>,
+    caption_entities: Option<Vec<MessageEntity>>,
+    reply_markup: Option<InlineKeyboardMarkup>,
+    input_message_content: Option<InputMessageContent>,
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder)]
+pub struct InlineQueryResultCachedAudio {
+    #[serde(rename = "type")]
+    #[builder(value = "audio")]
+    result_type: String,
+    id: String,
+    audio_file_id: String,
+    caption: Option<String>,
+    parse_mode: Option<String>,
+    caption_entities: Option<Vec<MessageEntity>>,
+    reply_markup: Option<InlineKeyboardMarkup>,
+    input_message_content: Option<InputMessageContent>,
+}
+
+/// content of the message to be sent instead of the inline query result
+#[derive(serde::Serialize)]
+#[serde(untagged)]
+pub enum InputMessageContent {
+    Text(InputTextMessageContent),
+    Location(InputLocationMessageContent),
+    Venue(InputVenueMessageContent),
+    Contact(InputContactMessageContent),
+    Invoice(InputInvoiceMessageContent),
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder)]
+pub struct InputTextMessageContent {
+    message_text: String,
+    parse_mode: Option<String>,
+    entities: Option<Vec<MessageEntity>>,
+    disable_web_page_preview: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder)]
+pub struct InputLocationMessageContent {
+    latitude: f64,
+    longitude: f64,
+    horizontal_accuracy: Option<f64>,
+    live_period: Option<u32>,
+    heading: Option<u16>,
+    proximity_alert_radius: Option<u32>,
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder)]
+pub struct InputVenueMessageContent {
+    latitude: f64,
+    longitude: f64,
+    title: String,
+    address: String,
+    foursquare_id: Option<String>,
+    foursquare_type: Option<String>,
+    google_place_id: Option<String>,
+    google_place_type: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder)]
+pub struct InputContactMessageContent {
+    phone_number: String,
+    first_name: String,
+    last_name: Option<String>,
+    vcard: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder)]
+pub struct InputInvoiceMessageContent {
+    title: String,
+    description: String,
+    payload: String,
+    provider_token: String,
+    currency: String,
+    prices: Vec<LabeledPrice>,
+    max_tip_amount: Option<usize>,
+    suggested_tip_amounts: Option<Vec<usize>>,
+    provider_data: Option<String>,
+    photo_url: Option<String>,
+    photo_size: Option<usize>,
+    photo_width: Option<usize>,
+    photo_height: Option<usize>,
+    need_name: Option<bool>,
+    need_phone_number: Option<bool>,
+    need_email: Option<bool>,
+    need_shipping_address: Option<bool>,
+    send_phone_number_to_provider: Option<bool>,
+    send_email_to_provider: Option<bool>,
+    is_flexible: Option<bool>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_results() {
+        let content = InputTextMessageContentBuilder::new("hello").build();
+        let article =
+            InlineQueryResultArticleBuilder::new("1", "Hello", InputMessageContent::Text(content))
+                .description("say hello")
+                .build();
+        let cached = InlineQueryResultCachedPhotoBuilder::new("2", "file-id").build();
+        let answer = AnswerInlineQueryBuilder::new(
+            "query",
+            vec![
+                InlineQueryResult::Article(article),
+                InlineQueryResult::CachedPhoto(cached),
+            ],
+        )
+        .cache_time(0)
+        .build();
+        assert_eq!(
+            serde_json::to_value(answer).unwrap(),
+            serde_json::json!({
+                "inline_query_id": "query",
+                "results": [
+                    {
+                        "type": "article",
+                        "id": "1",
+                        "title": "Hello",
+                        "input_message_content": {"message_text": "hello"},
+                        "description": "say hello"
+                    },
+                    {"type": "photo", "id": "2", "photo_file_id": "file-id"}
+                ],
+                "cache_time": 0
+            })
+        );
+    }
+}
@@ -0,0 +1,42 @@
+use serde_with::skip_serializing_none;
+
+use super::{ChatId, ReplyMarkup};
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct SendLocation {
+    chat_id: ChatId,
+    message_thread_id: Option<u64>,
+    latitude: f64,
+    longitude: f64,
+    horizontal_accuracy: Option<f64>,
+    /// 60 - 86400 seconds, the location can be updated by `EditMessageLiveLocation` in this period
+    live_period: Option<u32>,
+    heading: Option<u16>,
+    proximity_alert_radius: Option<u32>,
+    disable_notification: Option<bool>,
+    protect_content: Option<bool>,
+    reply_to_message_id: Option<u64>,
+    allow_sending_without_reply: Option<bool>,
+    reply_markup: Option<ReplyMarkup>,
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct SendVenue {
+    chat_id: ChatId,
+    message_thread_id: Option<u64>,
+    latitude: f64,
+    longitude: f64,
+    title: String,
+    address: String,
+    foursquare_id: Option<String>,
+    foursquare_type: Option<String>,
+    google_place_id: Option<String>,
+    google_place_type: Option<String>,
+    disable_notification: Option<bool>,
+    protect_content: Option<bool>,
+    reply_to_message_id: Option<u64>,
+    allow_sending_without_reply: Option<bool>,
+    reply_markup: Option<ReplyMarkup>,
+}
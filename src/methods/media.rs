@@ -1,8 +1,11 @@
 use serde_with::skip_serializing_none;
 
-use crate::types::message::MessageEntity;
+use crate::types::{
+    message::{Message, MessageEntity},
+    LargestPhoto,
+};
 
-use super::{ChatId, ReplyMarkup, SendFile};
+use super::{ChatId, ReplyMarkup, SendFile, TgMultipartMethod};
 
 macro_rules! impl_thumbnail {
     ($builder: path) => {
@@ -38,12 +41,6 @@ pub struct SendPhoto {
     reply_markup: Option<ReplyMarkup>,
 }
 
-impl SendPhoto {
-    pub fn is_multipart(&self) -> bool {
-        matches!(self.photo, SendFile::UploadInput { .. })
-    }
-}
-
 #[skip_serializing_none]
 #[derive(
     serde::Serialize,
@@ -75,13 +72,6 @@ pub struct SendAudio {
 
 impl_thumbnail!(SendAudioBuilder);
 
-impl SendAudio {
-    pub(crate) fn is_multipart(&self) -> bool {
-        matches!(self.audio, SendFile::UploadInput { .. })
-            || matches!(self.thumbnail, Some(SendFile::UploadInput { .. }))
-    }
-}
-
 #[skip_serializing_none]
 #[derive(
     serde::Serialize,
@@ -219,3 +209,47 @@ pub struct SendVideoNote {
 }
 
 impl_thumbnail!(SendVideoNote);
+
+/// Media methods whose upload can be replaced with the file_id of a previous upload,
+/// see [`crate::media_cache::MediaCache`]
+pub trait CacheableMethod: TgMultipartMethod {
+    fn media_mut(&mut self) -> &mut SendFile;
+
+    /// file_id of the media in the sent message
+    fn sent_file_id(message: &Message) -> Option<&str>;
+}
+
+macro_rules! impl_cacheable {
+    ($method: path, $field: ident) => {
+        impl CacheableMethod for $method {
+            fn media_mut(&mut self) -> &mut SendFile {
+                &mut self.$field
+            }
+
+            fn sent_file_id(message: &Message) -> Option<&str> {
+                message.$field.as_ref().map(|media| media.file_id.as_str())
+            }
+        }
+    };
+}
+
+impl CacheableMethod for SendPhoto {
+    fn media_mut(&mut self) -> &mut SendFile {
+        &mut self.photo
+    }
+
+    fn sent_file_id(message: &Message) -> Option<&str> {
+        message
+            .photo
+            .as_ref()?
+            .largest_photo()
+            .map(|size| size.file_id.as_str())
+    }
+}
+
+impl_cacheable!(SendAudio, audio);
+impl_cacheable!(SendDocument, document);
+impl_cacheable!(SendVideo, video);
+impl_cacheable!(SendAnimation, animation);
+impl_cacheable!(SendVoice, voice);
+impl_cacheable!(SendVideoNote, video_note);
@@ -1,16 +1,16 @@
-use std::mem;
-
 use serde_with::skip_serializing_none;
 
-use crate::types::message::MessageEntity;
+use crate::{client::Error, types::message::MessageEntity};
 
-use super::{ChatId, SendFile, TgMultipartMethod, UploadFile};
+use super::{AttachInput, AttachNames, ChatId, SendFile};
 
 #[skip_serializing_none]
-#[derive(serde::Serialize, tgbotool_derive::Builder)]
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::Multipart)]
+#[multipart(validate = "validate_media")]
 pub struct SendMediaGroup {
     chat_id: ChatId,
     message_thread_id: Option<u64>,
+    #[multipart(input)]
     media: Vec<Media>,
     disable_notification: Option<bool>,
     protect_content: Option<bool>,
@@ -18,73 +18,74 @@ pub struct SendMediaGroup {
     allow_sending_without_reply: Option<bool>,
 }
 
-impl TgMultipartMethod for SendMediaGroup {
-    fn method_name() -> String {
-        "sendMediaGroup".to_string()
-    }
+/// an album must contain 2-10 items
+pub const MIN_MEDIA: usize = 2;
+pub const MAX_MEDIA: usize = 10;
 
-    fn is_multipart(&self) -> bool {
-        for media in &self.media {
-            if media.is_multipart() {
-                return true;
-            }
-        }
-        false
-    }
+#[derive(Debug, thiserror::Error)]
+pub enum MediaGroupError {
+    #[error("media group needs at least {MIN_MEDIA} items, got {0}")]
+    TooFew(usize),
+    #[error("media group allows at most {MAX_MEDIA} items, got {0}")]
+    TooMany(usize),
+    #[error("audio can only be grouped with audio")]
+    MixedAudio,
+    #[error("documents can only be grouped with documents")]
+    MixedDocument,
+    #[error("animations can't be sent in a media group")]
+    Animation,
 }
 
-impl TryFrom<SendMediaGroup> for reqwest::multipart::Form {
-    type Error = serde_json::Error;
-
-    fn try_from(mut this: SendMediaGroup) -> Result<Self, Self::Error> {
-        use serde_json as json;
-        let mut form = reqwest::multipart::Form::new();
-
-        form = form.text("chat_id", json::to_string(&this.chat_id)?);
-
-        if let Some(message_thread_id) = this.message_thread_id {
-            form = form.text("message_thread_id", json::to_string(&message_thread_id)?);
-        }
-
-        for media in &mut this.media {
-            if let Some((file_name, part)) = media.get_file_part() {
-                form = form.part(file_name, part);
-            }
-        }
-        form = form.text("media", json::to_string(&this.media)?);
-
-        if let Some(disable_notification) = this.disable_notification {
-            form = form.text(
-                "disable_notification",
-                json::to_string(&disable_notification)?,
-            );
-        }
-
-        if let Some(protect_content) = this.protect_content {
-            form = form.text("protect_content", json::to_string(&protect_content)?);
-        }
-
-        if let Some(reply_to_message_id) = this.reply_to_message_id {
-            form = form.text(
-                "reply_to_message_id",
-                json::to_string(&reply_to_message_id)?,
-            );
+impl SendMediaGroup {
+    pub fn validate_media(&self) -> Result<(), MediaGroupError> {
+        match self.media.len() {
+            n if n < MIN_MEDIA => return Err(MediaGroupError::TooFew(n)),
+            n if n > MAX_MEDIA => return Err(MediaGroupError::TooMany(n)),
+            _ => {}
         }
+        validate_media(&self.media)
+    }
+}
 
-        if let Some(allow_sending_without_reply) = this.allow_sending_without_reply {
-            form = form.text(
-                "allow_sending_without_reply",
-                json::to_string(&allow_sending_without_reply)?,
-            );
-        }
+/// check the media types can be sent in the same album
+pub(crate) fn validate_media(media: &[Media]) -> Result<(), MediaGroupError> {
+    if media.iter().any(|m| matches!(m, Media::Animation(_))) {
+        return Err(MediaGroupError::Animation);
+    }
+    let count = |f: fn(&Media) -> bool| media.iter().filter(|m| f(m)).count();
+    let audios = count(|m| matches!(m, Media::Audio(_)));
+    if audios > 0 && audios < media.len() {
+        return Err(MediaGroupError::MixedAudio);
+    }
+    let documents = count(|m| matches!(m, Media::Document(_)));
+    if documents > 0 && documents < media.len() {
+        return Err(MediaGroupError::MixedDocument);
+    }
+    Ok(())
+}
 
-        Ok(form)
+/// split into albums of at most `MAX_MEDIA` items, evenly sized so no album is too small
+pub(crate) fn split_media(media: Vec<Media>) -> Result<Vec<Vec<Media>>, MediaGroupError> {
+    let len = media.len();
+    if len < MIN_MEDIA {
+        return Err(MediaGroupError::TooFew(len));
     }
+    let chunks = len.div_ceil(MAX_MEDIA);
+    let (base, extra) = (len / chunks, len % chunks);
+    let mut media = media.into_iter();
+    Ok((0..chunks)
+        .map(|i| {
+            let size = base + usize::from(i < extra);
+            media.by_ref().take(size).collect()
+        })
+        .collect())
 }
 
 #[derive(serde::Serialize)]
 #[serde(untagged)]
 pub enum Media {
+    /// only supported by `EditMessageMedia`
+    Animation(InputMediaAnimation),
     Audio(InputMediaAudio),
     Document(InputMediaDocument),
     Photo(InputMediaPhoto),
@@ -94,6 +95,7 @@ pub enum Media {
 impl Media {
     fn get_media_mut(&mut self) -> &mut SendFile {
         match self {
+            Media::Animation(m) => &mut m.media,
             Media::Audio(m) => &mut m.media,
             Media::Document(m) => &mut m.media,
             Media::Photo(m) => &mut m.media,
@@ -103,6 +105,7 @@ impl Media {
 
     fn get_media(&self) -> &SendFile {
         match self {
+            Media::Animation(m) => &m.media,
             Media::Audio(m) => &m.media,
             Media::Document(m) => &m.media,
             Media::Photo(m) => &m.media,
@@ -110,32 +113,64 @@ impl Media {
         }
     }
 
+    fn get_thumbnail_mut(&mut self) -> Option<&mut SendFile> {
+        match self {
+            Media::Animation(m) => m.thumbnail.as_mut(),
+            Media::Audio(m) => m.thumbnail.as_mut(),
+            Media::Document(m) => m.thumbnail.as_mut(),
+            Media::Photo(_) => None,
+            Media::Video(m) => m.thumbnail.as_mut(),
+        }
+    }
+
+    fn get_thumbnail(&self) -> Option<&SendFile> {
+        match self {
+            Media::Animation(m) => m.thumbnail.as_ref(),
+            Media::Audio(m) => m.thumbnail.as_ref(),
+            Media::Document(m) => m.thumbnail.as_ref(),
+            Media::Photo(_) => None,
+            Media::Video(m) => m.thumbnail.as_ref(),
+        }
+    }
+}
+
+impl AttachInput for Media {
     fn is_multipart(&self) -> bool {
-        matches!(self.get_media(), SendFile::UploadInput { .. })
+        self.get_media().is_upload() || self.get_thumbnail().is_some_and(SendFile::is_upload)
     }
 
-    fn get_file_part(&mut self) -> Option<(String, reqwest::multipart::Part)> {
-        use reqwest::multipart::Part;
-        let media = match self {
-            Media::Audio(m) => &mut m.media,
-            Media::Document(m) => &mut m.media,
-            Media::Photo(m) => &mut m.media,
-            Media::Video(m) => &mut m.media,
-        };
-        if let SendFile::UploadInput(UploadFile {
-            file_name,
-            file_bytes,
-        }) = media
-        {
-            let attach_name = file_name.clone();
-            let part = Part::bytes(mem::take(file_bytes)).file_name(mem::take(file_name));
-            *media = SendFile::FileIdOrUrl(format!("attach://{attach_name}"));
-            return Some((attach_name.clone(), part));
+    fn take_file_parts(
+        &mut self,
+        names: &mut AttachNames,
+    ) -> Result<Vec<(String, reqwest::multipart::Part)>, Error> {
+        let mut parts = Vec::new();
+        parts.extend(self.get_media_mut().take_attach_part(names)?);
+        if let Some(thumbnail) = self.get_thumbnail_mut() {
+            parts.extend(thumbnail.take_attach_part(names)?);
         }
-        None
+        Ok(parts)
     }
 }
 
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder)]
+pub struct InputMediaAnimation {
+    #[serde(rename = "type")]
+    #[builder(value = "animation")]
+    media_type: String,
+    /// file_id / http_url / attach
+    media: SendFile,
+    /// can only be uploaded, sent as attach
+    thumbnail: Option<SendFile>,
+    caption: Option<String>,
+    parse_mode: Option<String>,
+    caption_entities: Option<Vec<MessageEntity>>,
+    width: Option<usize>,
+    height: Option<usize>,
+    duration: Option<usize>,
+    has_spoiler: Option<bool>,
+}
+
 #[skip_serializing_none]
 #[derive(serde::Serialize, tgbotool_derive::Builder)]
 pub struct InputMediaAudio {
@@ -144,7 +179,8 @@ pub struct InputMediaAudio {
     media_type: String,
     /// file_id / http_url / attach
     media: SendFile,
-    thumbnail: Option<String>,
+    /// can only be uploaded, sent as attach
+    thumbnail: Option<SendFile>,
     caption: Option<String>,
     parse_mode: Option<String>,
     caption_entities: Option<Vec<MessageEntity>>,
@@ -161,7 +197,8 @@ pub struct InputMediaDocument {
     media_type: String,
     /// file_id / http_url / attach
     media: SendFile,
-    thumbnail: Option<String>,
+    /// can only be uploaded, sent as attach
+    thumbnail: Option<SendFile>,
     caption: Option<String>,
     parse_mode: Option<String>,
     caption_entities: Option<Vec<MessageEntity>>,
@@ -190,7 +227,8 @@ pub struct InputMediaVideo {
     media_type: String,
     /// file_id / http_url / attach
     media: SendFile,
-    thumbnail: Option<String>,
+    /// can only be uploaded, sent as attach
+    thumbnail: Option<SendFile>,
     caption: Option<String>,
     parse_mode: Option<String>,
     caption_entities: Option<Vec<MessageEntity>>,
@@ -200,3 +238,96 @@ pub struct InputMediaVideo {
     supports_streaming: Option<bool>,
     has_spoiler: Option<bool>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn photos(n: usize) -> Vec<Media> {
+        (0..n)
+            .map(|i| {
+                Media::Photo(
+                    InputMediaPhotoBuilder::new(SendFile::id_or_url(i.to_string())).build(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn validate_media_group() {
+        let group = |media| SendMediaGroupBuilder::new(ChatId::Chat(7), media).build();
+        assert!(matches!(
+            group(photos(1)).validate_media(),
+            Err(MediaGroupError::TooFew(1))
+        ));
+        assert!(matches!(
+            group(photos(11)).validate_media(),
+            Err(MediaGroupError::TooMany(11))
+        ));
+        assert!(group(photos(10)).validate_media().is_ok());
+
+        let mut media = photos(2);
+        media.push(Media::Document(
+            InputMediaDocumentBuilder::new(SendFile::id_or_url("doc")).build(),
+        ));
+        assert!(matches!(
+            group(media).validate_media(),
+            Err(MediaGroupError::MixedDocument)
+        ));
+    }
+
+    #[test]
+    fn thumbnail_parts() {
+        let video = |name| {
+            Media::Video(
+                InputMediaVideoBuilder::new(SendFile::upload(name, b"video".to_vec()))
+                    .thumbnail(SendFile::upload("thumb.jpg", b"thumb".to_vec()))
+                    .build(),
+            )
+        };
+        // same file names must not share an attach name
+        let mut media = vec![video("video.mp4"), video("video.mp4")];
+        assert!(media.is_multipart());
+        let names = media
+            .take_file_parts(&mut AttachNames::default())
+            .unwrap()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["file0", "file1", "file2", "file3"]);
+        assert_eq!(
+            serde_json::to_value(&media).unwrap(),
+            serde_json::json!([
+                {"type": "video", "media": "attach://file0", "thumbnail": "attach://file1"},
+                {"type": "video", "media": "attach://file2", "thumbnail": "attach://file3"}
+            ])
+        );
+
+        let animation =
+            Media::Animation(InputMediaAnimationBuilder::new(SendFile::id_or_url("gif")).build());
+        assert!(!animation.is_multipart());
+        assert!(matches!(
+            validate_media(&[animation]),
+            Err(MediaGroupError::Animation)
+        ));
+    }
+
+    #[test]
+    fn split_album() {
+        let sizes = |n| {
+            split_media(photos(n))
+                .unwrap()
+                .iter()
+                .map(Vec::len)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(sizes(2), [2]);
+        assert_eq!(sizes(10), [10]);
+        assert_eq!(sizes(11), [6, 5]);
+        assert_eq!(sizes(21), [7, 7, 7]);
+        assert!(matches!(
+            split_media(photos(1)),
+            Err(MediaGroupError::TooFew(1))
+        ));
+    }
+}
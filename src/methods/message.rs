@@ -2,7 +2,10 @@ use serde_with::skip_serializing_none;
 
 use crate::types::{message::MessageEntity, InlineKeyboardMarkup};
 
-use super::{ChatId, ReplyMarkup};
+use super::{
+    media_group::Media, AttachInput, AttachNames, ChatId, MessageTarget, ReplyMarkup,
+    TgMultipartMethod,
+};
 
 #[skip_serializing_none]
 #[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
@@ -51,12 +54,165 @@ pub struct CopyMessage {
 #[skip_serializing_none]
 #[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
 pub struct EditMessageText {
-    chat_id: Option<ChatId>,
-    message_id: Option<u64>,
-    inline_message_id: Option<String>,
+    #[serde(flatten)]
+    target: MessageTarget,
     text: String,
     parse_mode: Option<String>,
     entities: Option<Vec<MessageEntity>>,
     disable_web_page_preview: Option<bool>,
     reply_markup: Option<InlineKeyboardMarkup>,
 }
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct EditMessageCaption {
+    #[serde(flatten)]
+    target: MessageTarget,
+    caption: Option<String>,
+    parse_mode: Option<String>,
+    caption_entities: Option<Vec<MessageEntity>>,
+    reply_markup: Option<InlineKeyboardMarkup>,
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder)]
+pub struct EditMessageMedia {
+    #[serde(flatten)]
+    target: MessageTarget,
+    media: Media,
+    reply_markup: Option<InlineKeyboardMarkup>,
+}
+
+impl TgMultipartMethod for EditMessageMedia {
+    fn method_name() -> String {
+        "editMessageMedia".to_string()
+    }
+
+    fn is_multipart(&self) -> bool {
+        AttachInput::is_multipart(&self.media)
+    }
+}
+
+impl TryFrom<EditMessageMedia> for reqwest::multipart::Form {
+    type Error = crate::client::Error;
+
+    fn try_from(mut this: EditMessageMedia) -> Result<Self, Self::Error> {
+        use serde_json as json;
+        let mut form = this
+            .target
+            .append_to_form(reqwest::multipart::Form::new())?;
+
+        for (file_name, part) in this.media.take_file_parts(&mut AttachNames::default())? {
+            form = form.part(file_name, part);
+        }
+        form = form.text("media", json::to_string(&this.media)?);
+
+        if let Some(reply_markup) = this.reply_markup {
+            form = form.text("reply_markup", json::to_string(&reply_markup)?);
+        }
+
+        Ok(form)
+    }
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct EditMessageReplyMarkup {
+    #[serde(flatten)]
+    target: MessageTarget,
+    reply_markup: Option<InlineKeyboardMarkup>,
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct EditMessageLiveLocation {
+    #[serde(flatten)]
+    target: MessageTarget,
+    latitude: f64,
+    longitude: f64,
+    horizontal_accuracy: Option<f64>,
+    heading: Option<u16>,
+    proximity_alert_radius: Option<u32>,
+    reply_markup: Option<InlineKeyboardMarkup>,
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct StopMessageLiveLocation {
+    #[serde(flatten)]
+    target: MessageTarget,
+    reply_markup: Option<InlineKeyboardMarkup>,
+}
+
+/// returns the stopped `Poll`
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct StopPoll {
+    chat_id: ChatId,
+    message_id: u64,
+    reply_markup: Option<InlineKeyboardMarkup>,
+}
+
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct DeleteMessage {
+    chat_id: ChatId,
+    message_id: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{message::fixture::message_json, CallbackQuery};
+
+    use super::*;
+
+    #[test]
+    fn flatten_message_target() {
+        let chat = EditMessageCaptionBuilder::new(MessageTarget::chat(ChatId::Chat(-100), 42))
+            .caption("new caption")
+            .build();
+        assert_eq!(
+            serde_json::to_value(chat).unwrap(),
+            serde_json::json!({"chat_id": -100, "message_id": 42, "caption": "new caption"})
+        );
+
+        let inline = EditMessageReplyMarkupBuilder::new(MessageTarget::inline("abc")).build();
+        assert_eq!(
+            serde_json::to_value(inline).unwrap(),
+            serde_json::json!({"inline_message_id": "abc"})
+        );
+    }
+
+    #[test]
+    fn edit_callback_message() {
+        let callback_query: CallbackQuery = serde_json::from_value(serde_json::json!({
+            "id": "1",
+            "from": {"id": 7, "is_bot": false, "first_name": "a"},
+            "message": message_json(12, serde_json::json!({})),
+            "chat_instance": "-1",
+            "data": "clicked"
+        }))
+        .unwrap();
+        let target = MessageTarget::from_callback_query(&callback_query).unwrap();
+        let edit = EditMessageTextBuilder::new(target, "done").build();
+        assert_eq!(
+            serde_json::to_value(edit).unwrap(),
+            serde_json::json!({"chat_id": 7, "message_id": 12, "text": "done"})
+        );
+
+        let callback_query: CallbackQuery = serde_json::from_str(
+            r#"
+                {
+                    "id": "2",
+                    "from": {"id": 7, "is_bot": false, "first_name": "a"},
+                    "inline_message_id": "inline",
+                    "chat_instance": "-1"
+                }
+            "#,
+        )
+        .unwrap();
+        assert!(matches!(
+            MessageTarget::from_callback_query(&callback_query),
+            Some(MessageTarget::Inline { inline_message_id }) if inline_message_id == "inline"
+        ));
+    }
+}
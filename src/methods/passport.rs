@@ -0,0 +1,70 @@
+use crate::types::passport::EncryptedPassportElementType;
+
+/// the user will not be able to re-submit their passport until the errors are fixed
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct SetPassportDataErrors {
+    user_id: u64,
+    errors: Vec<PassportElementError>,
+}
+
+/// hashes are the base64 encoded hashes from `Credentials`
+#[derive(serde::Serialize)]
+#[serde(tag = "source", rename_all = "snake_case")]
+pub enum PassportElementError {
+    #[serde(rename = "data")]
+    DataField {
+        #[serde(rename = "type")]
+        element_type: EncryptedPassportElementType,
+        field_name: String,
+        data_hash: String,
+        message: String,
+    },
+    FrontSide {
+        #[serde(rename = "type")]
+        element_type: EncryptedPassportElementType,
+        file_hash: String,
+        message: String,
+    },
+    ReverseSide {
+        #[serde(rename = "type")]
+        element_type: EncryptedPassportElementType,
+        file_hash: String,
+        message: String,
+    },
+    Selfie {
+        #[serde(rename = "type")]
+        element_type: EncryptedPassportElementType,
+        file_hash: String,
+        message: String,
+    },
+    File {
+        #[serde(rename = "type")]
+        element_type: EncryptedPassportElementType,
+        file_hash: String,
+        message: String,
+    },
+    Files {
+        #[serde(rename = "type")]
+        element_type: EncryptedPassportElementType,
+        file_hashes: Vec<String>,
+        message: String,
+    },
+    TranslationFile {
+        #[serde(rename = "type")]
+        element_type: EncryptedPassportElementType,
+        file_hash: String,
+        message: String,
+    },
+    TranslationFiles {
+        #[serde(rename = "type")]
+        element_type: EncryptedPassportElementType,
+        file_hashes: Vec<String>,
+        message: String,
+    },
+    Unspecified {
+        #[serde(rename = "type")]
+        element_type: EncryptedPassportElementType,
+        element_hash: String,
+        message: String,
+    },
+}
@@ -0,0 +1,117 @@
+use serde_with::skip_serializing_none;
+
+use crate::types::{
+    payment::{LabeledPrice, ShippingOption},
+    InlineKeyboardMarkup,
+};
+
+use super::ChatId;
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct SendInvoice {
+    chat_id: ChatId,
+    message_thread_id: Option<u64>,
+    title: String,
+    description: String,
+    /// not displayed to the user, use it for internal processes
+    payload: String,
+    provider_token: String,
+    currency: String,
+    prices: Vec<LabeledPrice>,
+    max_tip_amount: Option<usize>,
+    suggested_tip_amounts: Option<Vec<usize>>,
+    start_parameter: Option<String>,
+    provider_data: Option<String>,
+    photo_url: Option<String>,
+    photo_size: Option<usize>,
+    photo_width: Option<usize>,
+    photo_height: Option<usize>,
+    need_name: Option<bool>,
+    need_phone_number: Option<bool>,
+    need_email: Option<bool>,
+    need_shipping_address: Option<bool>,
+    send_phone_number_to_provider: Option<bool>,
+    send_email_to_provider: Option<bool>,
+    /// set if the final price depends on the shipping method
+    is_flexible: Option<bool>,
+    disable_notification: Option<bool>,
+    protect_content: Option<bool>,
+    reply_to_message_id: Option<u64>,
+    allow_sending_without_reply: Option<bool>,
+    /// the first button must be a Pay button
+    reply_markup: Option<InlineKeyboardMarkup>,
+}
+
+/// returns the created invoice link as `String`
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct CreateInvoiceLink {
+    title: String,
+    description: String,
+    payload: String,
+    provider_token: String,
+    currency: String,
+    prices: Vec<LabeledPrice>,
+    max_tip_amount: Option<usize>,
+    suggested_tip_amounts: Option<Vec<usize>>,
+    provider_data: Option<String>,
+    photo_url: Option<String>,
+    photo_size: Option<usize>,
+    photo_width: Option<usize>,
+    photo_height: Option<usize>,
+    need_name: Option<bool>,
+    need_phone_number: Option<bool>,
+    need_email: Option<bool>,
+    need_shipping_address: Option<bool>,
+    send_phone_number_to_provider: Option<bool>,
+    send_email_to_provider: Option<bool>,
+    is_flexible: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct AnswerShippingQuery {
+    shipping_query_id: String,
+    ok: bool,
+    /// required if ok is true
+    shipping_options: Option<Vec<ShippingOption>>,
+    /// required if ok is false
+    error_message: Option<String>,
+}
+
+impl AnswerShippingQuery {
+    pub fn ok(shipping_query_id: &str, shipping_options: Vec<ShippingOption>) -> Self {
+        AnswerShippingQueryBuilder::new(shipping_query_id, true)
+            .shipping_options(shipping_options)
+            .build()
+    }
+
+    pub fn error(shipping_query_id: &str, error_message: &str) -> Self {
+        AnswerShippingQueryBuilder::new(shipping_query_id, false)
+            .error_message(error_message)
+            .build()
+    }
+}
+
+/// must be answered within 10 seconds after the pre-checkout query was sent
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct AnswerPreCheckoutQuery {
+    pre_checkout_query_id: String,
+    ok: bool,
+    /// required if ok is false
+    error_message: Option<String>,
+}
+
+impl AnswerPreCheckoutQuery {
+    pub fn ok(pre_checkout_query_id: &str) -> Self {
+        AnswerPreCheckoutQueryBuilder::new(pre_checkout_query_id, true).build()
+    }
+
+    pub fn error(pre_checkout_query_id: &str, error_message: &str) -> Self {
+        AnswerPreCheckoutQueryBuilder::new(pre_checkout_query_id, false)
+            .error_message(error_message)
+            .build()
+    }
+}
@@ -0,0 +1,212 @@
+use serde_with::skip_serializing_none;
+
+use crate::{
+    client::Error,
+    types::{message::Message, MaskPosition, StickerType},
+};
+
+use super::{media::CacheableMethod, AttachInput, AttachNames, ChatId, ReplyMarkup, SendFile};
+
+/// returns the sent `Message`
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::Multipart)]
+pub struct SendSticker {
+    chat_id: ChatId,
+    message_thread_id: Option<u64>,
+    /// file_id / http_url (.webp) / upload (.webp, .tgs, .webm)
+    #[multipart(normal)]
+    sticker: SendFile,
+    emoji: Option<String>,
+    disable_notification: Option<bool>,
+    protect_content: Option<bool>,
+    reply_to_message_id: Option<u64>,
+    allow_sending_without_reply: Option<bool>,
+    reply_markup: Option<ReplyMarkup>,
+}
+
+impl CacheableMethod for SendSticker {
+    fn media_mut(&mut self) -> &mut SendFile {
+        &mut self.sticker
+    }
+
+    fn sent_file_id(message: &Message) -> Option<&str> {
+        message
+            .sticker
+            .as_ref()
+            .map(|sticker| sticker.file_id.as_str())
+    }
+}
+
+/// returns `StickerSet`
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct GetStickerSet {
+    name: String,
+}
+
+/// returns `Vec<Sticker>`
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct GetCustomEmojiStickers {
+    /// at most 200 custom emoji identifiers
+    custom_emoji_ids: Vec<String>,
+}
+
+/// returns the uploaded `File`, its file_id can be used in `InputSticker`
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::Multipart)]
+pub struct UploadStickerFile {
+    user_id: u64,
+    #[multipart(normal)]
+    sticker: SendFile,
+    sticker_format: StickerFormat,
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::Multipart)]
+pub struct CreateNewStickerSet {
+    user_id: u64,
+    /// must end in `_by_<bot_username>`
+    name: String,
+    title: String,
+    /// 1-50 stickers
+    #[multipart(input)]
+    stickers: Vec<InputSticker>,
+    sticker_format: StickerFormat,
+    sticker_type: Option<StickerType>,
+    needs_repainting: Option<bool>,
+}
+
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::Multipart)]
+pub struct AddStickerToSet {
+    user_id: u64,
+    name: String,
+    #[multipart(input)]
+    sticker: InputSticker,
+}
+
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct SetStickerPositionInSet {
+    /// file_id
+    sticker: String,
+    /// zero-based
+    position: u32,
+}
+
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct DeleteStickerFromSet {
+    sticker: String,
+}
+
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct SetStickerEmojiList {
+    sticker: String,
+    /// 1-20 emoji
+    emoji_list: Vec<String>,
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct SetStickerKeywords {
+    sticker: String,
+    /// 0-20 keywords, `None` removes the keywords
+    keywords: Option<Vec<String>>,
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::Multipart)]
+pub struct SetStickerSetThumbnail {
+    name: String,
+    user_id: u64,
+    /// `None` drops the thumbnail and the first sticker is used
+    #[multipart(normal)]
+    thumbnail: Option<SendFile>,
+}
+
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct DeleteStickerSet {
+    name: String,
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder)]
+pub struct InputSticker {
+    /// file_id / http_url / upload, uploads are sent as attach://<file_name>
+    sticker: SendFile,
+    /// 1-20 emoji
+    emoji_list: Vec<String>,
+    /// "mask" stickers only
+    mask_position: Option<MaskPosition>,
+    /// "regular" and "custom_emoji" stickers only
+    keywords: Option<Vec<String>>,
+}
+
+impl AttachInput for InputSticker {
+    fn is_multipart(&self) -> bool {
+        self.sticker.is_upload()
+    }
+
+    fn take_file_parts(
+        &mut self,
+        names: &mut AttachNames,
+    ) -> Result<Vec<(String, reqwest::multipart::Part)>, Error> {
+        Ok(self.sticker.take_attach_part(names)?.into_iter().collect())
+    }
+}
+
+#[derive(serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StickerFormat {
+    /// .webp or .png
+    Static,
+    /// .tgs
+    Animated,
+    /// .webm
+    Video,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn attach_input_stickers() {
+        let stickers = vec![
+            InputStickerBuilder::new(
+                SendFile::upload("cat.webp", b"cat".to_vec()),
+                vec!["🐱".to_owned()],
+            )
+            .build(),
+            InputStickerBuilder::new(SendFile::id_or_url("file-id"), vec!["🐶".to_owned()])
+                .keywords(vec!["dog".to_owned()])
+                .build(),
+        ];
+        let mut method = CreateNewStickerSetBuilder::new(
+            1,
+            "pets_by_bot",
+            "Pets",
+            stickers,
+            StickerFormat::Static,
+        )
+        .build();
+        assert!(crate::methods::TgMultipartMethod::is_multipart(&method));
+
+        let parts = method
+            .stickers
+            .take_file_parts(&mut AttachNames::default())
+            .unwrap();
+        assert_eq!(parts.len(), 1);
+        assert_eq!(parts[0].0, "file0");
+        assert_eq!(
+            serde_json::to_value(&method).unwrap(),
+            serde_json::json!({
+                "user_id": 1,
+                "name": "pets_by_bot",
+                "title": "Pets",
+                "stickers": [
+                    {"sticker": "attach://file0", "emoji_list": ["🐱"]},
+                    {"sticker": "file-id", "emoji_list": ["🐶"], "keywords": ["dog"]}
+                ],
+                "sticker_format": "static"
+            })
+        );
+        assert!(!crate::methods::TgMultipartMethod::is_multipart(&method));
+    }
+}
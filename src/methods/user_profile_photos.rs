@@ -0,0 +1,12 @@
+use serde_with::skip_serializing_none;
+
+/// returns `UserProfilePhotos`
+#[skip_serializing_none]
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct GetUserProfilePhotos {
+    user_id: u64,
+    /// sequential number of the first photo to be returned
+    offset: Option<u32>,
+    /// 1-100, defaults to 100
+    limit: Option<u32>,
+}
@@ -0,0 +1,8 @@
+use super::inline_query::InlineQueryResult;
+
+/// returns `SentWebAppMessage`
+#[derive(serde::Serialize, tgbotool_derive::Builder, tgbotool_derive::TgMethod)]
+pub struct AnswerWebAppQuery {
+    web_app_query_id: String,
+    result: InlineQueryResult,
+}
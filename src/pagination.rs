@@ -0,0 +1,199 @@
+use std::future::Future;
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+
+use crate::methods::inline_query::{AnswerInlineQueryBuilder, InlineQueryResult};
+
+/// telegram limits `next_offset` to 64 bytes
+pub const MAX_OFFSET_LEN: usize = 64;
+/// telegram accepts no more than 50 results per inline query answer
+pub const MAX_PAGE_SIZE: usize = 50;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Invalid offset: {0}")]
+    InvalidOffset(String),
+    #[error("Offset too long: {0} bytes")]
+    OffsetTooLong(usize),
+}
+
+/// One page of inline query results
+pub struct Page {
+    pub results: Vec<InlineQueryResult>,
+    /// `None` if this is the last page
+    pub next_offset: Option<String>,
+}
+
+impl Page {
+    pub fn answer(self, inline_query_id: &str) -> AnswerInlineQueryBuilder {
+        // an empty next_offset tells telegram there are no more results
+        let next_offset = self.next_offset.unwrap_or_default();
+        AnswerInlineQueryBuilder::new(inline_query_id, self.results).next_offset(&next_offset)
+    }
+}
+
+/// Slices results according to `InlineQuery::offset`.
+///
+/// Offsets are opaque cursors: any serializable value, encoded as url safe base64 json.
+pub struct Paginator {
+    page_size: usize,
+}
+
+impl Paginator {
+    pub fn new(page_size: usize) -> Self {
+        Self {
+            page_size: page_size.clamp(1, MAX_PAGE_SIZE),
+        }
+    }
+
+    /// Take the page of `results` at `offset`, the cursor is the index of the next result
+    pub fn page<I>(&self, offset: &str, results: I) -> Result<Page, Error>
+    where
+        I: IntoIterator<Item = InlineQueryResult>,
+    {
+        let start = decode_cursor::<usize>(offset)?.unwrap_or_default();
+        let mut results = results.into_iter().skip(start);
+        let page = results.by_ref().take(self.page_size).collect::<Vec<_>>();
+        let next_offset = match results.next() {
+            Some(_) => Some(encode_cursor(&(start + page.len()))?),
+            None => None,
+        };
+        Ok(Page {
+            results: page,
+            next_offset,
+        })
+    }
+
+    /// Fetch the page at `offset` with `fetch(cursor, page_size)`.
+    ///
+    /// `cursor` is `None` for the first page, `fetch` returns the results and the cursor of the next page.
+    pub async fn fetch<C, F, Fut, E>(&self, offset: &str, fetch: F) -> Result<Page, E>
+    where
+        C: serde::Serialize + serde::de::DeserializeOwned,
+        F: FnOnce(Option<C>, usize) -> Fut,
+        Fut: Future<Output = Result<(Vec<InlineQueryResult>, Option<C>), E>>,
+        E: From<Error>,
+    {
+        let cursor = decode_cursor(offset)?;
+        let (results, next) = fetch(cursor, self.page_size).await?;
+        let next_offset = next.map(|cursor| encode_cursor(&cursor)).transpose()?;
+        Ok(Page {
+            results,
+            next_offset,
+        })
+    }
+}
+
+pub fn encode_cursor<C>(cursor: &C) -> Result<String, Error>
+where
+    C: serde::Serialize,
+{
+    let json = serde_json::to_vec(cursor).map_err(|e| Error::InvalidOffset(e.to_string()))?;
+    let offset = URL_SAFE_NO_PAD.encode(json);
+    if offset.len() > MAX_OFFSET_LEN {
+        return Err(Error::OffsetTooLong(offset.len()));
+    }
+    Ok(offset)
+}
+
+/// `None` for the empty offset of the first query
+pub fn decode_cursor<C>(offset: &str) -> Result<Option<C>, Error>
+where
+    C: serde::de::DeserializeOwned,
+{
+    if offset.is_empty() {
+        return Ok(None);
+    }
+    let json = URL_SAFE_NO_PAD
+        .decode(offset)
+        .map_err(|e| Error::InvalidOffset(e.to_string()))?;
+    serde_json::from_slice(&json)
+        .map(Some)
+        .map_err(|e| Error::InvalidOffset(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::methods::inline_query::InlineQueryResultCachedStickerBuilder;
+
+    use super::*;
+
+    fn results(range: std::ops::Range<usize>) -> Vec<InlineQueryResult> {
+        range
+            .map(|i| {
+                InlineQueryResult::CachedSticker(
+                    InlineQueryResultCachedStickerBuilder::new(&i.to_string(), "sticker").build(),
+                )
+            })
+            .collect()
+    }
+
+    fn ids(page: &Page) -> Vec<String> {
+        page.results
+            .iter()
+            .map(|r| {
+                serde_json::to_value(r).unwrap()["id"]
+                    .as_str()
+                    .unwrap()
+                    .to_owned()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn page_iter() {
+        let paginator = Paginator::new(2);
+
+        let first = paginator.page("", results(0..5)).unwrap();
+        assert_eq!(ids(&first), ["0", "1"]);
+
+        let second = paginator
+            .page(first.next_offset.as_deref().unwrap(), results(0..5))
+            .unwrap();
+        assert_eq!(ids(&second), ["2", "3"]);
+
+        let last = paginator
+            .page(second.next_offset.as_deref().unwrap(), results(0..5))
+            .unwrap();
+        assert_eq!(ids(&last), ["4"]);
+        assert!(last.next_offset.is_none());
+
+        assert!(matches!(
+            paginator.page("not a cursor", results(0..5)),
+            Err(Error::InvalidOffset(_))
+        ));
+    }
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct Cursor {
+        after: String,
+    }
+
+    #[tokio::test]
+    async fn page_fetch() {
+        let paginator = Paginator::new(10);
+        let page = paginator
+            .fetch("", |cursor: Option<Cursor>, page_size| async move {
+                assert!(cursor.is_none());
+                assert_eq!(page_size, 10);
+                let next = Cursor {
+                    after: "key-9".to_owned(),
+                };
+                Ok::<_, Error>((results(0..10), Some(next)))
+            })
+            .await
+            .unwrap();
+        assert_eq!(page.results.len(), 10);
+
+        let offset = page.next_offset.unwrap();
+        let page = paginator
+            .fetch(&offset, |cursor: Option<Cursor>, _| async move {
+                assert_eq!(cursor.unwrap().after, "key-9");
+                Ok::<_, Error>((results(10..12), None))
+            })
+            .await
+            .unwrap();
+        assert_eq!(ids(&page), ["10", "11"]);
+        assert!(page.next_offset.is_none());
+    }
+}
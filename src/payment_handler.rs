@@ -0,0 +1,375 @@
+use std::{future::Future, pin::Pin, time::Duration};
+
+use crate::{
+    client::{Client, Error},
+    methods::payment::{AnswerPreCheckoutQuery, AnswerShippingQuery},
+    types::{
+        payment::{PreCheckoutQuery, ShippingOption, ShippingQuery, SuccessfulPayment},
+        update::{Update, UpdateType},
+        user::User,
+    },
+};
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+type PreCheckoutHook<P> = Box<
+    dyn for<'a> Fn(&'a PreCheckoutQuery, &'a P) -> BoxFuture<'a, Result<(), String>> + Send + Sync,
+>;
+
+type ShippingHook<P> = Box<
+    dyn for<'a> Fn(&'a ShippingQuery, &'a P) -> BoxFuture<'a, Result<Vec<ShippingOption>, String>>
+        + Send
+        + Sync,
+>;
+
+/// telegram cancels the checkout if the pre-checkout query is not answered within 10 seconds
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(8);
+const INVALID_PAYLOAD: &str = "Invalid invoice, please try again";
+const TIMEOUT: &str = "The order could not be confirmed in time, please try again";
+const NO_SHIPPING: &str = "Shipping is not available";
+
+/// Answers shipping and pre-checkout queries through the registered hooks.
+///
+/// The invoice payload is the json of `P`, see [`encode_payload`].
+pub struct PaymentHandler<P> {
+    client: Client,
+    timeout: Duration,
+    pre_checkout: Option<PreCheckoutHook<P>>,
+    shipping: Option<ShippingHook<P>>,
+}
+
+#[allow(clippy::large_enum_variant)]
+pub enum PaymentEvent<P> {
+    /// the shipping query has been answered
+    Shipping {
+        query: ShippingQuery,
+        accepted: bool,
+    },
+    /// the pre-checkout query has been answered
+    PreCheckout {
+        query: PreCheckoutQuery,
+        accepted: bool,
+    },
+    Paid(Payment<P>),
+    /// not related to payments
+    Other(Update),
+}
+
+pub struct Payment<P> {
+    pub chat_id: i64,
+    pub message_id: u64,
+    pub from: Option<User>,
+    /// `Err` if the invoice payload is not the json of `P` (e.g. a legacy invoice),
+    /// the raw payload is kept in `payment.invoice_payload`
+    pub payload: std::result::Result<P, serde_json::Error>,
+    pub payment: SuccessfulPayment,
+}
+
+pub fn encode_payload<P>(payload: &P) -> Result<String>
+where
+    P: serde::Serialize,
+{
+    Ok(serde_json::to_string(payload)?)
+}
+
+impl<P> PaymentHandler<P>
+where
+    P: serde::de::DeserializeOwned + Send + Sync,
+{
+    pub fn new(client: Client) -> Self {
+        Self {
+            client,
+            timeout: DEFAULT_TIMEOUT,
+            pre_checkout: None,
+            shipping: None,
+        }
+    }
+
+    /// Time limit of the hooks, the query is rejected when exceeded
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Validate the order before checkout (stock check, payload verification),
+    /// `Err` rejects the checkout with the message shown to the user.
+    pub fn on_pre_checkout<F>(mut self, hook: F) -> Self
+    where
+        F: for<'a> Fn(&'a PreCheckoutQuery, &'a P) -> BoxFuture<'a, Result<(), String>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.pre_checkout = Some(Box::new(hook));
+        self
+    }
+
+    /// Shipping options for flexible invoices,
+    /// `Err` rejects the shipping address with the message shown to the user.
+    pub fn on_shipping<F>(mut self, hook: F) -> Self
+    where
+        F: for<'a> Fn(
+                &'a ShippingQuery,
+                &'a P,
+            ) -> BoxFuture<'a, Result<Vec<ShippingOption>, String>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.shipping = Some(Box::new(hook));
+        self
+    }
+
+    pub async fn handle(&self, update: Update) -> Result<PaymentEvent<P>> {
+        match update.update_type {
+            UpdateType::ShippingQuery(query) => {
+                let accepted = self.answer_shipping(&query).await?;
+                Ok(PaymentEvent::Shipping { query, accepted })
+            }
+            UpdateType::PreCheckoutQuery(query) => {
+                let accepted = self.answer_pre_checkout(&query).await?;
+                Ok(PaymentEvent::PreCheckout { query, accepted })
+            }
+            UpdateType::Message(mut message) => match message.successful_payment.take() {
+                Some(payment) => Ok(PaymentEvent::Paid(Payment {
+                    chat_id: message.chat_id(),
+                    message_id: message.message_id,
+                    from: message.from,
+                    // the money is already taken, never drop the payment
+                    payload: serde_json::from_str(&payment.invoice_payload),
+                    payment,
+                })),
+                None => Ok(PaymentEvent::Other(Update {
+                    update_id: update.update_id,
+                    update_type: UpdateType::Message(message),
+                })),
+            },
+            update_type => Ok(PaymentEvent::Other(Update {
+                update_id: update.update_id,
+                update_type,
+            })),
+        }
+    }
+
+    async fn answer_shipping(&self, query: &ShippingQuery) -> Result<bool> {
+        let verdict = self.shipping_verdict(query).await;
+        let accepted = verdict.is_ok();
+        let answer = match verdict {
+            Ok(options) => AnswerShippingQuery::ok(&query.id, options),
+            Err(message) => AnswerShippingQuery::error(&query.id, &message),
+        };
+        self.client.send_ok(answer).await?;
+        Ok(accepted)
+    }
+
+    async fn answer_pre_checkout(&self, query: &PreCheckoutQuery) -> Result<bool> {
+        let verdict = self.pre_checkout_verdict(query).await;
+        let answer = match &verdict {
+            Ok(()) => AnswerPreCheckoutQuery::ok(&query.id),
+            Err(message) => AnswerPreCheckoutQuery::error(&query.id, message),
+        };
+        self.client.send_ok(answer).await?;
+        Ok(verdict.is_ok())
+    }
+
+    /// the shipping options, or the error shown to the user
+    async fn shipping_verdict(&self, query: &ShippingQuery) -> Result<Vec<ShippingOption>, String> {
+        let payload = serde_json::from_str::<P>(&query.invoice_payload)
+            .map_err(|_| INVALID_PAYLOAD.to_owned())?;
+        let hook = self
+            .shipping
+            .as_ref()
+            .ok_or_else(|| NO_SHIPPING.to_owned())?;
+        tokio::time::timeout(self.timeout, hook(query, &payload))
+            .await
+            .map_err(|_| TIMEOUT.to_owned())?
+    }
+
+    /// `Err` is the error shown to the user, the checkout is accepted without a hook
+    async fn pre_checkout_verdict(&self, query: &PreCheckoutQuery) -> Result<(), String> {
+        let payload = serde_json::from_str::<P>(&query.invoice_payload)
+            .map_err(|_| INVALID_PAYLOAD.to_owned())?;
+        let Some(hook) = &self.pre_checkout else {
+            return Ok(());
+        };
+        tokio::time::timeout(self.timeout, hook(query, &payload))
+            .await
+            .map_err(|_| TIMEOUT.to_owned())?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::message::fixture::message_json;
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct Order {
+        item: String,
+        quantity: u32,
+    }
+
+    fn paid_update(invoice_payload: &str) -> Update {
+        serde_json::from_value(serde_json::json!({
+            "update_id": 1,
+            "message": message_json(3, serde_json::json!({
+                "successful_payment": {
+                    "currency": "USD",
+                    "total_amount": 1000,
+                    "invoice_payload": invoice_payload,
+                    "telegram_payment_charge_id": "tg",
+                    "provider_payment_charge_id": "provider"
+                }
+            }))
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn successful_payment() {
+        let client = Client::new("token", reqwest::Client::new());
+        let handler = PaymentHandler::<Order>::new(client)
+            .on_pre_checkout(|_, order| {
+                Box::pin(async move {
+                    match order.quantity {
+                        0 => Err("Empty order".to_owned()),
+                        _ => Ok(()),
+                    }
+                })
+            })
+            .on_shipping(|_, _| Box::pin(async move { Ok(Vec::new()) }));
+
+        let payload = encode_payload(&Order {
+            item: "book".to_owned(),
+            quantity: 2,
+        })
+        .unwrap();
+        let update = paid_update(&payload);
+        let Ok(PaymentEvent::Paid(paid)) = handler.handle(update).await else {
+            panic!("expected a successful payment")
+        };
+        assert_eq!(paid.chat_id, 7);
+        assert_eq!(paid.payload.unwrap().item, "book");
+        assert_eq!(paid.payment.total_amount, 1000);
+
+        let update: Update = serde_json::from_value(serde_json::json!({
+            "update_id": 2,
+            "message": message_json(4, serde_json::json!({"text": "hello"}))
+        }))
+        .unwrap();
+        assert!(matches!(
+            handler.handle(update).await,
+            Ok(PaymentEvent::Other(Update { update_id: 2, .. }))
+        ));
+    }
+
+    #[tokio::test]
+    async fn invalid_payment_payload() {
+        let client = Client::new("token", reqwest::Client::new());
+        let handler = PaymentHandler::<Order>::new(client);
+        let update = paid_update("legacy-order-42");
+        let Ok(PaymentEvent::Paid(paid)) = handler.handle(update).await else {
+            panic!("expected a successful payment")
+        };
+        assert!(paid.payload.is_err());
+        assert_eq!(paid.payment.invoice_payload, "legacy-order-42");
+        assert_eq!(paid.payment.telegram_payment_charge_id, "tg");
+    }
+
+    fn shipping_query(invoice_payload: &str) -> ShippingQuery {
+        serde_json::from_value(serde_json::json!({
+            "id": "shipping",
+            "from": {"id": 7, "is_bot": false, "first_name": "a"},
+            "invoice_payload": invoice_payload,
+            "shipping_address": {
+                "country_code": "DE", "state": "", "city": "Berlin",
+                "street_line1": "a", "street_line2": "", "post_code": "10115"
+            }
+        }))
+        .unwrap()
+    }
+
+    fn pre_checkout_query(invoice_payload: &str) -> PreCheckoutQuery {
+        serde_json::from_value(serde_json::json!({
+            "id": "pre-checkout",
+            "from": {"id": 7, "is_bot": false, "first_name": "a"},
+            "currency": "USD",
+            "total_amount": 1000,
+            "invoice_payload": invoice_payload
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn payment_verdicts() {
+        let client = Client::new("token", reqwest::Client::new());
+        let payload = encode_payload(&Order {
+            item: "book".to_owned(),
+            quantity: 2,
+        })
+        .unwrap();
+
+        // no hooks: the checkout is accepted, shipping is not available
+        let handler = PaymentHandler::<Order>::new(client.clone());
+        assert_eq!(
+            handler
+                .pre_checkout_verdict(&pre_checkout_query(&payload))
+                .await,
+            Ok(())
+        );
+        assert_eq!(
+            handler
+                .shipping_verdict(&shipping_query(&payload))
+                .await
+                .err()
+                .as_deref(),
+            Some(NO_SHIPPING)
+        );
+        assert_eq!(
+            handler
+                .pre_checkout_verdict(&pre_checkout_query("legacy"))
+                .await,
+            Err(INVALID_PAYLOAD.to_owned())
+        );
+        assert_eq!(
+            handler
+                .shipping_verdict(&shipping_query("legacy"))
+                .await
+                .err()
+                .as_deref(),
+            Some(INVALID_PAYLOAD)
+        );
+
+        // hooks slower than the timeout
+        let handler = PaymentHandler::<Order>::new(client)
+            .timeout(Duration::from_secs(1))
+            .on_pre_checkout(|_, _| {
+                Box::pin(async move {
+                    tokio::time::sleep(Duration::from_secs(5)).await;
+                    Ok(())
+                })
+            })
+            .on_shipping(|_, _| {
+                Box::pin(async move {
+                    tokio::time::sleep(Duration::from_secs(5)).await;
+                    Ok(Vec::new())
+                })
+            });
+        assert_eq!(
+            handler
+                .pre_checkout_verdict(&pre_checkout_query(&payload))
+                .await,
+            Err(TIMEOUT.to_owned())
+        );
+        assert_eq!(
+            handler
+                .shipping_verdict(&shipping_query(&payload))
+                .await
+                .err()
+                .as_deref(),
+            Some(TIMEOUT)
+        );
+    }
+}
@@ -0,0 +1,342 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    client::{Client, Error},
+    methods::bot::{
+        DeleteMyCommandsBuilder, GetChatMenuButtonBuilder, GetMyCommandsBuilder,
+        GetMyDescriptionBuilder, GetMyNameBuilder, GetMyShortDescriptionBuilder,
+        SetChatMenuButtonBuilder, SetMyCommandsBuilder, SetMyDescriptionBuilder, SetMyNameBuilder,
+        SetMyShortDescriptionBuilder,
+    },
+    types::{BotCommand, BotDescription, BotName, BotShortDescription, MenuButton},
+};
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// The desired state of the bot profile, `None` fields are left as they are.
+///
+/// ```toml
+/// name = "Weather Bot"
+/// commands = [{ command = "forecast", description = "Weather forecast" }]
+/// menu_button = { type = "commands" }
+///
+/// [languages.de]
+/// name = "Wetter Bot"
+/// commands = [{ command = "forecast", description = "Wettervorhersage" }]
+/// ```
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+pub struct BotProfile {
+    /// used for users without a dedicated language
+    #[serde(flatten)]
+    pub default: LocalizedProfile,
+    /// keyed by two-letter ISO 639-1 language code
+    #[serde(default)]
+    pub languages: BTreeMap<String, LocalizedProfile>,
+    /// default menu button for private chats
+    pub menu_button: Option<MenuButton>,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+pub struct LocalizedProfile {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub short_description: Option<String>,
+    /// empty commands are deleted
+    pub commands: Option<Vec<BotCommand>>,
+}
+
+/// A set* call issued by [`Client::sync_profile`], `language_code` is `None` for the default language
+#[derive(Debug, PartialEq, Eq)]
+pub enum ProfileChange {
+    Name { language_code: Option<String> },
+    Description { language_code: Option<String> },
+    ShortDescription { language_code: Option<String> },
+    Commands { language_code: Option<String> },
+    MenuButton,
+}
+
+impl BotProfile {
+    /// default profile first, then the languages ordered by code
+    fn localized(&self) -> impl Iterator<Item = (Option<&str>, &LocalizedProfile)> {
+        std::iter::once((None, &self.default)).chain(
+            self.languages
+                .iter()
+                .map(|(language_code, localized)| (Some(language_code.as_str()), localized)),
+        )
+    }
+
+    fn get_localized(&self, language_code: Option<&str>) -> Option<&LocalizedProfile> {
+        match language_code {
+            Some(language_code) => self.languages.get(language_code),
+            None => Some(&self.default),
+        }
+    }
+
+    /// The set* calls needed to turn `current` into this profile
+    pub fn changes_from(&self, current: &BotProfile) -> Vec<ProfileChange> {
+        let mut changes = Vec::new();
+        let empty = LocalizedProfile::default();
+        for (language_code, desired) in self.localized() {
+            let current = current.get_localized(language_code).unwrap_or(&empty);
+            let language = || language_code.map(ToOwned::to_owned);
+            if differs(&desired.name, &current.name) {
+                changes.push(ProfileChange::Name {
+                    language_code: language(),
+                });
+            }
+            if differs(&desired.description, &current.description) {
+                changes.push(ProfileChange::Description {
+                    language_code: language(),
+                });
+            }
+            if differs(&desired.short_description, &current.short_description) {
+                changes.push(ProfileChange::ShortDescription {
+                    language_code: language(),
+                });
+            }
+            if differs(&desired.commands, &current.commands) {
+                changes.push(ProfileChange::Commands {
+                    language_code: language(),
+                });
+            }
+        }
+        if differs(&self.menu_button, &current.menu_button) {
+            changes.push(ProfileChange::MenuButton);
+        }
+        changes
+    }
+}
+
+/// `None` means the field is not managed
+fn differs<T>(desired: &Option<T>, current: &Option<T>) -> bool
+where
+    T: PartialEq,
+{
+    desired.is_some() && desired != current
+}
+
+impl Client {
+    /// Read the current profile and only set the fields differing from `profile`
+    pub async fn sync_profile(&self, profile: &BotProfile) -> Result<Vec<ProfileChange>> {
+        let current = self.get_profile(profile).await?;
+        let changes = profile.changes_from(&current);
+        for change in &changes {
+            self.apply_change(profile, change).await?;
+        }
+        Ok(changes)
+    }
+
+    /// the current state of the fields managed by `profile`
+    async fn get_profile(&self, profile: &BotProfile) -> Result<BotProfile> {
+        let mut current = BotProfile::default();
+        for (language_code, desired) in profile.localized() {
+            let localized = self.get_localized(language_code, desired).await?;
+            match language_code {
+                Some(language_code) => {
+                    current
+                        .languages
+                        .insert(language_code.to_owned(), localized);
+                }
+                None => current.default = localized,
+            }
+        }
+        if profile.menu_button.is_some() {
+            current.menu_button = Some(self.send(GetChatMenuButtonBuilder::new().build()).await?);
+        }
+        Ok(current)
+    }
+
+    async fn get_localized(
+        &self,
+        language_code: Option<&str>,
+        desired: &LocalizedProfile,
+    ) -> Result<LocalizedProfile> {
+        let mut current = LocalizedProfile::default();
+        if desired.name.is_some() {
+            let mut get = GetMyNameBuilder::new();
+            if let Some(language_code) = language_code {
+                get = get.language_code(language_code);
+            }
+            let name: BotName = self.send(get.build()).await?;
+            current.name = Some(name.name);
+        }
+        if desired.description.is_some() {
+            let mut get = GetMyDescriptionBuilder::new();
+            if let Some(language_code) = language_code {
+                get = get.language_code(language_code);
+            }
+            let description: BotDescription = self.send(get.build()).await?;
+            current.description = Some(description.description);
+        }
+        if desired.short_description.is_some() {
+            let mut get = GetMyShortDescriptionBuilder::new();
+            if let Some(language_code) = language_code {
+                get = get.language_code(language_code);
+            }
+            let short_description: BotShortDescription = self.send(get.build()).await?;
+            current.short_description = Some(short_description.short_description);
+        }
+        if desired.commands.is_some() {
+            let mut get = GetMyCommandsBuilder::new();
+            if let Some(language_code) = language_code {
+                get = get.language_code(language_code);
+            }
+            current.commands = Some(self.send(get.build()).await?);
+        }
+        Ok(current)
+    }
+
+    async fn apply_change(&self, profile: &BotProfile, change: &ProfileChange) -> Result<()> {
+        let localized = |language_code: &Option<String>| {
+            profile
+                .get_localized(language_code.as_deref())
+                .unwrap_or(&profile.default)
+        };
+        match change {
+            ProfileChange::Name { language_code } => {
+                let mut set = SetMyNameBuilder::new();
+                if let Some(name) = localized(language_code).name.as_deref() {
+                    set = set.name(name);
+                }
+                if let Some(language_code) = language_code {
+                    set = set.language_code(language_code);
+                }
+                self.send_ok(set.build()).await
+            }
+            ProfileChange::Description { language_code } => {
+                let mut set = SetMyDescriptionBuilder::new();
+                if let Some(description) = localized(language_code).description.as_deref() {
+                    set = set.description(description);
+                }
+                if let Some(language_code) = language_code {
+                    set = set.language_code(language_code);
+                }
+                self.send_ok(set.build()).await
+            }
+            ProfileChange::ShortDescription { language_code } => {
+                let mut set = SetMyShortDescriptionBuilder::new();
+                if let Some(short_description) =
+                    localized(language_code).short_description.as_deref()
+                {
+                    set = set.short_description(short_description);
+                }
+                if let Some(language_code) = language_code {
+                    set = set.language_code(language_code);
+                }
+                self.send_ok(set.build()).await
+            }
+            ProfileChange::Commands { language_code } => {
+                let commands = localized(language_code)
+                    .commands
+                    .clone()
+                    .unwrap_or_default();
+                if commands.is_empty() {
+                    let mut delete = DeleteMyCommandsBuilder::new();
+                    if let Some(language_code) = language_code {
+                        delete = delete.language_code(language_code);
+                    }
+                    self.send_ok(delete.build()).await
+                } else {
+                    let mut set = SetMyCommandsBuilder::new(commands);
+                    if let Some(language_code) = language_code {
+                        set = set.language_code(language_code);
+                    }
+                    self.send_ok(set.build()).await
+                }
+            }
+            ProfileChange::MenuButton => {
+                let mut set = SetChatMenuButtonBuilder::new();
+                if let Some(menu_button) = &profile.menu_button {
+                    set = set.menu_button(menu_button.clone());
+                }
+                self.send_ok(set.build()).await
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROFILE: &str = r#"
+        name = "Weather Bot"
+        commands = [{ command = "forecast", description = "Weather forecast" }]
+        menu_button = { type = "commands" }
+
+        [languages.de]
+        name = "Wetter Bot"
+        commands = []
+    "#;
+
+    fn command(command: &str, description: &str) -> BotCommand {
+        BotCommand {
+            command: command.to_owned(),
+            description: description.to_owned(),
+        }
+    }
+
+    #[test]
+    fn deserialize_profile() {
+        let profile: BotProfile = toml::from_str(PROFILE).unwrap();
+        assert_eq!(profile.default.name.as_deref(), Some("Weather Bot"));
+        assert!(profile.default.description.is_none());
+        assert_eq!(profile.default.commands.unwrap()[0].command, "forecast");
+        assert_eq!(profile.menu_button, Some(MenuButton::Commands));
+        assert_eq!(profile.languages["de"].commands.as_deref(), Some(&[][..]));
+
+        let profile: BotProfile = serde_json::from_value(serde_json::json!({
+            "name": "Weather Bot",
+            "languages": {"de": {"name": "Wetter Bot"}}
+        }))
+        .unwrap();
+        assert_eq!(profile.languages["de"].name.as_deref(), Some("Wetter Bot"));
+    }
+
+    #[test]
+    fn profile_changes() {
+        let profile: BotProfile = toml::from_str(PROFILE).unwrap();
+
+        // the current state as read by the get* calls
+        let mut current = BotProfile {
+            default: LocalizedProfile {
+                name: Some("Weather Bot".to_owned()),
+                commands: Some(vec![command("forecast", "Weather forecast")]),
+                ..Default::default()
+            },
+            languages: BTreeMap::from([(
+                "de".to_owned(),
+                LocalizedProfile {
+                    name: Some("Weather Bot".to_owned()),
+                    commands: Some(Vec::new()),
+                    ..Default::default()
+                },
+            )]),
+            menu_button: Some(MenuButton::Default),
+        };
+        assert_eq!(
+            profile.changes_from(&current),
+            [
+                ProfileChange::Name {
+                    language_code: Some("de".to_owned())
+                },
+                ProfileChange::MenuButton,
+            ]
+        );
+
+        current.languages.get_mut("de").unwrap().name = Some("Wetter Bot".to_owned());
+        current.menu_button = Some(MenuButton::Commands);
+        assert!(profile.changes_from(&current).is_empty());
+
+        // unmanaged fields are never changed
+        current.default.description = Some("Forecasts".to_owned());
+        current.default.commands = Some(vec![command("forecast", "Forecast")]);
+        assert_eq!(
+            profile.changes_from(&current),
+            [ProfileChange::Commands {
+                language_code: None
+            }]
+        );
+        assert!(profile.changes_from(&profile).is_empty());
+    }
+}
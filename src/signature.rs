@@ -0,0 +1,91 @@
+use std::{
+    collections::BTreeMap,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Verification error of data signed with the bot token (Web App init data, Login Widget)
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Missing field: {0}")]
+    MissingField(&'static str),
+    #[error("Invalid field {0}: {1}")]
+    InvalidField(&'static str, String),
+    #[error("Invalid hash")]
+    InvalidHash,
+    #[error("Signed data expired")]
+    Expired,
+}
+
+pub(crate) fn hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC can take key of any size");
+    mac.update(data);
+    mac.finalize().into_bytes().to_vec()
+}
+
+/// all received fields except `hash`, sorted alphabetically in the format `key=<value>`
+/// and joined with a line feed
+pub(crate) fn data_check_string(fields: &BTreeMap<String, String>) -> String {
+    fields
+        .iter()
+        .filter(|(key, _)| *key != "hash")
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// constant time comparison of the hex encoded `hash` with the HMAC-SHA-256 signature of the fields
+pub(crate) fn verify(fields: &BTreeMap<String, String>, secret_key: &[u8], hash: &str) -> bool {
+    let Ok(hash) = hex::decode(hash) else {
+        return false;
+    };
+    let mut mac = HmacSha256::new_from_slice(secret_key).expect("HMAC can take key of any size");
+    mac.update(data_check_string(fields).as_bytes());
+    mac.verify_slice(&hash).is_ok()
+}
+
+/// `auth_date` is older than `max_age`
+pub(crate) fn is_expired(auth_date: u64, max_age: Duration) -> bool {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    now.saturating_sub(auth_date) > max_age.as_secs()
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+
+    pub(crate) const BOT_TOKEN: &str = "123456:ABC-DEF1234ghIkl-zyx57W2v1u123ew11";
+
+    pub(crate) fn now() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+    }
+
+    /// hex encoded signature of the fields
+    pub(crate) fn sign(fields: &[(&str, String)], secret_key: &[u8]) -> String {
+        let map = fields
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.clone()))
+            .collect::<BTreeMap<_, _>>();
+        hex::encode(hmac_sha256(secret_key, data_check_string(&map).as_bytes()))
+    }
+
+    /// query string of the fields with the `hash` appended
+    pub(crate) fn signed_query(fields: &[(&str, String)], secret_key: &[u8]) -> String {
+        form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(fields.iter().map(|(k, v)| (*k, v.as_str())))
+            .append_pair("hash", &sign(fields, secret_key))
+            .finish()
+    }
+}
@@ -133,6 +133,27 @@ pub enum ChatMember {
     },
 }
 
+#[skip_serializing_none]
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct ChatAdministratorRights {
+    pub is_anonymous: bool,
+    pub can_manage_chat: bool,
+    pub can_delete_messages: bool,
+    pub can_manage_video_chats: bool,
+    pub can_restrict_members: bool,
+    pub can_promote_members: bool,
+    pub can_change_info: bool,
+    pub can_invite_users: bool,
+    /// channels only
+    pub can_post_messages: Option<bool>,
+    /// channels only
+    pub can_edit_messages: Option<bool>,
+    /// groups and supergroups only
+    pub can_pin_messages: Option<bool>,
+    /// supergroups only
+    pub can_manage_topics: Option<bool>,
+}
+
 #[derive(serde::Serialize, serde::Deserialize)]
 pub struct ChatInviteLink {
     pub invite_link: String,
@@ -182,7 +203,7 @@ pub struct Document {
     pub file_size: Option<i64>,
 }
 
-#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct PhotoSize {
     pub file_id: String,
     pub file_unique_id: String,
@@ -191,6 +212,17 @@ pub struct PhotoSize {
     pub file_size: Option<i64>,
 }
 
+/// Photos are sent in several sizes
+pub trait LargestPhoto {
+    fn largest_photo(&self) -> Option<&PhotoSize>;
+}
+
+impl LargestPhoto for [PhotoSize] {
+    fn largest_photo(&self) -> Option<&PhotoSize> {
+        self.iter().max_by_key(|size| size.width * size.height)
+    }
+}
+
 #[derive(serde::Serialize, serde::Deserialize)]
 pub struct Sticker {
     pub file_id: String,
@@ -236,6 +268,18 @@ pub enum StickerType {
     CustomEmoji,
 }
 
+#[skip_serializing_none]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct StickerSet {
+    pub name: String,
+    pub title: String,
+    pub sticker_type: StickerType,
+    pub is_animated: bool,
+    pub is_video: bool,
+    pub stickers: Vec<Sticker>,
+    pub thumbnail: Option<PhotoSize>,
+}
+
 #[derive(serde::Serialize, serde::Deserialize)]
 pub struct Video {
     pub file_id: String,
@@ -356,6 +400,15 @@ pub struct ProximityAlertTriggered {
     pub distance: usize,
 }
 
+#[skip_serializing_none]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct ForumTopic {
+    pub message_thread_id: u64,
+    pub name: String,
+    pub icon_color: usize,
+    pub icon_custom_emoji_id: Option<String>,
+}
+
 #[derive(serde::Serialize, serde::Deserialize)]
 pub struct ForumTopicCreated {
     pub name: String,
@@ -405,6 +458,16 @@ pub struct WebAppData {
     pub button_text: String,
 }
 
+impl WebAppData {
+    /// `data` sent by `Telegram.WebApp.sendData` as json
+    pub fn json<T>(&self) -> serde_json::Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        serde_json::from_str(&self.data)
+    }
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Default)]
 pub struct InlineKeyboardMarkup {
     pub inline_keyboard: Vec<Vec<InlineKeyboardButton>>,
@@ -440,11 +503,77 @@ pub struct InlineKeyboardButton {
     pub pay: Option<bool>,
 }
 
-#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct WebAppInfo {
     pub url: String,
 }
 
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MenuButton {
+    /// opens the list of bot commands
+    Commands,
+    WebApp {
+        text: String,
+        web_app: WebAppInfo,
+    },
+    /// no specific value for the menu button
+    Default,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct BotCommand {
+    /// 1-32 characters, lowercase letters, digits and underscores
+    pub command: String,
+    pub description: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct BotName {
+    pub name: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct BotDescription {
+    pub description: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct BotShortDescription {
+    pub short_description: String,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct WebAppUser {
+    pub id: u64,
+    pub is_bot: Option<bool>,
+    pub first_name: String,
+    pub last_name: Option<String>,
+    pub username: Option<String>,
+    pub language_code: Option<String>,
+    pub is_premium: Option<bool>,
+    pub added_to_attachment_menu: Option<bool>,
+    pub allows_write_to_pm: Option<bool>,
+    pub photo_url: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct WebAppChat {
+    pub id: i64,
+    #[serde(rename = "type")]
+    pub chat_type: String,
+    pub title: String,
+    pub username: Option<String>,
+    pub photo_url: Option<String>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct SentWebAppMessage {
+    pub inline_message_id: Option<String>,
+}
+
 #[derive(serde::Serialize, serde::Deserialize)]
 pub struct LoginUrl {
     pub url: String,
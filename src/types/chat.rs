@@ -6,19 +6,12 @@ use super::message::Message;
 #[skip_serializing_none]
 #[derive(serde::Serialize, serde::Deserialize)]
 pub struct Chat {
-    ///
     pub id: i64,
-    ///
     pub photo: Option<ChatPhoto>,
-    ///
     pub pinned_message: Option<Box<Message>>,
-    ///
     pub message_auto_delete_time: Option<u64>,
-    ///
     pub has_hidden_members: Option<bool>,
-    ///
     pub has_protected_content: Option<bool>,
-    ///
     #[serde(flatten)]
     #[serde(rename = "type")]
     pub chat_type: ChatType,
@@ -1,4 +1,4 @@
-use super::{message::MessageEntity, Animation, PhotoSize};
+use super::{message::MessageEntity, user::User, Animation, PhotoSize};
 
 #[derive(serde::Serialize, serde::Deserialize)]
 pub struct Game {
@@ -6,6 +6,13 @@ pub struct Game {
     pub description: String,
     pub photo: Vec<PhotoSize>,
     pub text: Option<String>,
-    pub text_entites: Vec<MessageEntity>,
+    pub text_entities: Option<Vec<MessageEntity>>,
     pub animation: Option<Animation>,
 }
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct GameHighScore {
+    pub position: usize,
+    pub user: User,
+    pub score: u64,
+}
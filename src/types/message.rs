@@ -11,7 +11,7 @@ use super::{
     payment::{Invoice, SuccessfulPayment},
     Animation, Audio, ChatShared, Contact, Dice, Document, ForumTopicClosed, ForumTopicCreated,
     ForumTopicEdited, ForumTopicReopened, GeneralForumTopicHidden, GeneralForumTopicUnHidden,
-    InlineKeyboardMarkup, Location, MessageAutoDeleteTimerChanged, PhotoSize, Poll,
+    InlineKeyboardMarkup, LargestPhoto, Location, MessageAutoDeleteTimerChanged, PhotoSize, Poll,
     ProximityAlertTriggered, Sticker, User, UserShared, Venue, Video, VideoChatEnded,
     VideoChatParticipantsInvited, VideoChatScheduled, VideoChatStarted, VideoNote, Voice,
     WebAppData, WriteAccessAllowed,
@@ -105,13 +105,122 @@ impl Message {
     fn is_command(&self) -> bool {
         self.entities
             .as_ref()
-            .and_then(|entities| entities.get(0))
+            .and_then(|entities| entities.first())
             .is_some_and(|en| en.entity_type == MessageEntityType::BotCommand && en.offset == 0)
     }
 
     pub fn chat_id(&self) -> i64 {
         self.chat.id
     }
+
+    /// The file attached to the message, the largest size for photos
+    pub fn media(&self) -> Option<MessageMedia<'_>> {
+        if let Some(photo) = self
+            .photo
+            .as_deref()
+            .and_then(|sizes| sizes.largest_photo())
+        {
+            return Some(MessageMedia::Photo(photo));
+        }
+        // animations also fill in `document` for backward compatibility
+        if let Some(animation) = &self.animation {
+            return Some(MessageMedia::Animation(animation));
+        }
+        let media = if let Some(audio) = &self.audio {
+            MessageMedia::Audio(audio)
+        } else if let Some(document) = &self.document {
+            MessageMedia::Document(document)
+        } else if let Some(sticker) = &self.sticker {
+            MessageMedia::Sticker(sticker)
+        } else if let Some(video) = &self.video {
+            MessageMedia::Video(video)
+        } else if let Some(video_note) = &self.video_note {
+            MessageMedia::VideoNote(video_note)
+        } else if let Some(voice) = &self.voice {
+            MessageMedia::Voice(voice)
+        } else {
+            return None;
+        };
+        Some(media)
+    }
+}
+
+pub enum MessageMedia<'a> {
+    Animation(&'a Animation),
+    Audio(&'a Audio),
+    Document(&'a Document),
+    Photo(&'a PhotoSize),
+    Sticker(&'a Sticker),
+    Video(&'a Video),
+    VideoNote(&'a VideoNote),
+    Voice(&'a Voice),
+}
+
+impl MessageMedia<'_> {
+    /// can be used to download or reuse the file
+    pub fn file_id(&self) -> &str {
+        match self {
+            MessageMedia::Animation(m) => &m.file_id,
+            MessageMedia::Audio(m) => &m.file_id,
+            MessageMedia::Document(m) => &m.file_id,
+            MessageMedia::Photo(m) => &m.file_id,
+            MessageMedia::Sticker(m) => &m.file_id,
+            MessageMedia::Video(m) => &m.file_id,
+            MessageMedia::VideoNote(m) => &m.file_id,
+            MessageMedia::Voice(m) => &m.file_id,
+        }
+    }
+
+    /// same over time and for different bots, can't be used to download or reuse the file
+    pub fn file_unique_id(&self) -> &str {
+        match self {
+            MessageMedia::Animation(m) => &m.file_unique_id,
+            MessageMedia::Audio(m) => &m.file_unique_id,
+            MessageMedia::Document(m) => &m.file_unique_id,
+            MessageMedia::Photo(m) => &m.file_unique_id,
+            MessageMedia::Sticker(m) => &m.file_unique_id,
+            MessageMedia::Video(m) => &m.file_unique_id,
+            MessageMedia::VideoNote(m) => &m.file_unique_id,
+            MessageMedia::Voice(m) => &m.file_unique_id,
+        }
+    }
+
+    pub fn file_size(&self) -> Option<i64> {
+        match self {
+            MessageMedia::Animation(m) => m.file_size,
+            MessageMedia::Audio(m) => m.file_size,
+            MessageMedia::Document(m) => m.file_size,
+            MessageMedia::Photo(m) => m.file_size,
+            MessageMedia::Sticker(m) => m.file_size,
+            MessageMedia::Video(m) => m.file_size,
+            MessageMedia::VideoNote(m) => m.file_size,
+            MessageMedia::Voice(m) => m.file_size,
+        }
+    }
+
+    pub fn mime_type(&self) -> Option<&str> {
+        match self {
+            MessageMedia::Animation(m) => m.mime_type.as_deref(),
+            MessageMedia::Audio(m) => m.mime_type.as_deref(),
+            MessageMedia::Document(m) => m.mime_type.as_deref(),
+            MessageMedia::Video(m) => m.mime_type.as_deref(),
+            MessageMedia::Voice(m) => m.mime_type.as_deref(),
+            MessageMedia::Photo(_) | MessageMedia::Sticker(_) | MessageMedia::VideoNote(_) => None,
+        }
+    }
+
+    pub fn file_name(&self) -> Option<&str> {
+        match self {
+            MessageMedia::Animation(m) => m.file_name.as_deref(),
+            MessageMedia::Audio(m) => m.file_name.as_deref(),
+            MessageMedia::Document(m) => m.file_name.as_deref(),
+            MessageMedia::Video(m) => m.file_name.as_deref(),
+            MessageMedia::Photo(_)
+            | MessageMedia::Sticker(_)
+            | MessageMedia::VideoNote(_)
+            | MessageMedia::Voice(_) => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, tgbotool_derive::Builder)]
@@ -333,4 +442,54 @@ mod tests {
             ["#上班", "https://www.baidu.com"]
         )
     }
+
+    #[test]
+    fn message_media() {
+        let message: Message = serde_json::from_value(fixture::message_json(
+            1,
+            serde_json::json!({
+                "animation": {
+                    "file_id": "animation", "file_unique_id": "a", "width": 1, "height": 1,
+                    "duration": 1, "file_name": "cat.mp4", "mime_type": "video/mp4"
+                },
+                "document": {"file_id": "animation", "file_unique_id": "a", "file_name": "cat.mp4"}
+            }),
+        ))
+        .unwrap();
+        let media = message.media().unwrap();
+        assert!(matches!(media, MessageMedia::Animation(_)));
+        assert_eq!(media.file_name(), Some("cat.mp4"));
+        assert_eq!(media.mime_type(), Some("video/mp4"));
+
+        let message: Message = serde_json::from_value(fixture::message_json(2, serde_json::json!({
+            "photo": [
+                {"file_id": "small", "file_unique_id": "s", "width": 90, "height": 90, "file_size": 10},
+                {"file_id": "large", "file_unique_id": "l", "width": 800, "height": 800, "file_size": 100}
+            ]
+        })))
+        .unwrap();
+        let media = message.media().unwrap();
+        assert_eq!(media.file_id(), "large");
+        assert_eq!(media.file_size(), Some(100));
+        assert!(media.mime_type().is_none());
+    }
+}
+
+/// shared fixtures of the test modules
+#[cfg(test)]
+pub(crate) mod fixture {
+    use serde_json::{json, Value};
+
+    /// json of a message in a private chat, `fields` are added to it
+    pub(crate) fn message_json(message_id: u64, fields: Value) -> Value {
+        let mut message = json!({
+            "message_id": message_id,
+            "date": 0,
+            "chat": {"id": 7, "type": "private", "first_name": "a"},
+        });
+        if let (Some(message), Value::Object(fields)) = (message.as_object_mut(), fields) {
+            message.extend(fields);
+        }
+        message
+    }
 }
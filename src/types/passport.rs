@@ -1,9 +1,12 @@
+use serde_with::skip_serializing_none;
+
 #[derive(serde::Serialize, serde::Deserialize)]
 pub struct PassportData {
     pub data: Vec<EncryptedPassportElement>,
-    pub credentials: EnctrytedCredentials,
+    pub credentials: EncryptedCredentials,
 }
 
+#[skip_serializing_none]
 #[derive(serde::Serialize, serde::Deserialize)]
 pub struct EncryptedPassportElement {
     #[serde(rename = "type")]
@@ -15,19 +18,18 @@ pub struct EncryptedPassportElement {
     pub front_side: Option<PassportFile>,
     pub reverse_side: Option<PassportFile>,
     pub selfie: Option<PassportFile>,
-    pub trasnlation: Option<Vec<PassportFile>>,
+    pub translation: Option<Vec<PassportFile>>,
     pub hash: String,
 }
 
 #[derive(serde::Serialize, serde::Deserialize)]
-pub struct EnctrytedCredentials {
+pub struct EncryptedCredentials {
     pub data: String,
     pub hash: String,
     pub secret: String,
 }
 
-#[derive(serde::Serialize, serde::Deserialize)]
-#[serde(tag = "type")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum EncryptedPassportElementType {
     PersonalDetails,
@@ -41,6 +43,8 @@ pub enum EncryptedPassportElementType {
     RentalAgreement,
     PassportRegistration,
     TemporaryRegistration,
+    PhoneNumber,
+    Email,
 }
 
 #[derive(serde::Serialize, serde::Deserialize)]
@@ -50,3 +54,106 @@ pub struct PassportFile {
     pub file_size: i64,
     pub file_date: u64,
 }
+
+/// Decrypted `EncryptedCredentials::data`
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct Credentials {
+    pub secure_data: SecureData,
+    /// the nonce the bot passed when requesting the data
+    pub nonce: String,
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct SecureData {
+    pub personal_details: Option<SecureValue>,
+    pub passport: Option<SecureValue>,
+    pub internal_passport: Option<SecureValue>,
+    pub driver_license: Option<SecureValue>,
+    pub identity_card: Option<SecureValue>,
+    pub address: Option<SecureValue>,
+    pub utility_bill: Option<SecureValue>,
+    pub bank_statement: Option<SecureValue>,
+    pub rental_agreement: Option<SecureValue>,
+    pub passport_registration: Option<SecureValue>,
+    pub temporary_registration: Option<SecureValue>,
+}
+
+impl SecureData {
+    pub fn get(&self, element_type: EncryptedPassportElementType) -> Option<&SecureValue> {
+        use EncryptedPassportElementType as T;
+        match element_type {
+            T::PersonalDetails => self.personal_details.as_ref(),
+            T::Passport => self.passport.as_ref(),
+            T::InternalPassport => self.internal_passport.as_ref(),
+            T::DriverLicense => self.driver_license.as_ref(),
+            T::IdentityCard => self.identity_card.as_ref(),
+            T::Address => self.address.as_ref(),
+            T::UtilityBill => self.utility_bill.as_ref(),
+            T::BankStatement => self.bank_statement.as_ref(),
+            T::RentalAgreement => self.rental_agreement.as_ref(),
+            T::PassportRegistration => self.passport_registration.as_ref(),
+            T::TemporaryRegistration => self.temporary_registration.as_ref(),
+            T::PhoneNumber | T::Email => None,
+        }
+    }
+}
+
+#[skip_serializing_none]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct SecureValue {
+    pub data: Option<DataCredentials>,
+    pub front_side: Option<FileCredentials>,
+    pub reverse_side: Option<FileCredentials>,
+    pub selfie: Option<FileCredentials>,
+    pub translation: Option<Vec<FileCredentials>>,
+    pub files: Option<Vec<FileCredentials>>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct DataCredentials {
+    pub data_hash: String,
+    pub secret: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct FileCredentials {
+    pub file_hash: String,
+    pub secret: String,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct PersonalDetails {
+    pub first_name: String,
+    pub last_name: String,
+    pub middle_name: Option<String>,
+    /// DD.MM.YYYY
+    pub birth_date: String,
+    /// "male" or "female"
+    pub gender: String,
+    /// ISO 3166-1 alpha-2
+    pub country_code: String,
+    pub residence_country_code: String,
+    pub first_name_native: Option<String>,
+    pub last_name_native: Option<String>,
+    pub middle_name_native: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct ResidentialAddress {
+    pub street_line1: String,
+    pub street_line2: Option<String>,
+    pub city: String,
+    pub state: Option<String>,
+    pub country_code: String,
+    pub post_code: String,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct IdDocumentData {
+    pub document_no: String,
+    /// DD.MM.YYYY
+    pub expiry_date: Option<String>,
+}
@@ -24,7 +24,7 @@ pub struct Invoice {
     pub description: String,
     pub start_parameter: String,
     pub currency: String,
-    pub otal_amount: usize,
+    pub total_amount: usize,
 }
 
 #[derive(serde::Serialize, serde::Deserialize)]
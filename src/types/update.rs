@@ -15,6 +15,7 @@ pub struct Update {
 }
 
 #[skip_serializing_none]
+#[allow(clippy::large_enum_variant)]
 #[derive(serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum UpdateType {
@@ -1,5 +1,7 @@
 use serde_with::skip_serializing_none;
 
+use super::{LargestPhoto, PhotoSize};
+
 /// This object represents a Telegram user or bot
 #[skip_serializing_none]
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -27,3 +29,19 @@ pub struct User {
     /// Optional. True, if the bot supports inline queries. Returned only in `getMe`.
     pub supports_inline_queries: Option<bool>,
 }
+
+/// This object represents a user's profile pictures
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct UserProfilePhotos {
+    /// Total number of profile pictures the target user has
+    pub total_count: u32,
+    /// Requested profile pictures (in up to 4 sizes each)
+    pub photos: Vec<Vec<PhotoSize>>,
+}
+
+impl UserProfilePhotos {
+    /// The largest size of each requested profile picture
+    pub fn largest_photos(&self) -> impl Iterator<Item = &PhotoSize> {
+        self.photos.iter().filter_map(|sizes| sizes.largest_photo())
+    }
+}
@@ -0,0 +1,148 @@
+use std::{collections::BTreeMap, time::Duration};
+
+use serde_with::skip_serializing_none;
+
+pub use crate::signature::Error;
+use crate::{
+    signature,
+    types::{WebAppChat, WebAppUser},
+};
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// `Telegram.WebApp.initData` passed to the bot's backend
+#[skip_serializing_none]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct WebAppInitData {
+    /// used to send a message through `AnswerWebAppQuery`
+    pub query_id: Option<String>,
+    pub user: Option<WebAppUser>,
+    pub receiver: Option<WebAppUser>,
+    pub chat: Option<WebAppChat>,
+    pub chat_type: Option<String>,
+    pub chat_instance: Option<String>,
+    pub start_param: Option<String>,
+    pub can_send_after: Option<u64>,
+    /// unix time when the form was opened
+    pub auth_date: u64,
+    pub hash: String,
+}
+
+impl WebAppInitData {
+    /// Parse the query string without checking the signature,
+    /// the data must not be trusted before [`WebAppInitData::validate`]
+    pub fn parse(init_data: &str) -> Result<Self> {
+        Self::from_fields(&parse_fields(init_data))
+    }
+
+    /// Check the signature with the bot token and reject data older than `max_age`
+    pub fn validate(init_data: &str, bot_token: &str, max_age: Duration) -> Result<Self> {
+        let fields = parse_fields(init_data);
+        let hash = fields.get("hash").ok_or(Error::MissingField("hash"))?;
+        let secret_key = signature::hmac_sha256(b"WebAppData", bot_token.as_bytes());
+        if !signature::verify(&fields, &secret_key, hash) {
+            return Err(Error::InvalidHash);
+        }
+
+        let data = Self::from_fields(&fields)?;
+        if signature::is_expired(data.auth_date, max_age) {
+            return Err(Error::Expired);
+        }
+        Ok(data)
+    }
+
+    fn from_fields(fields: &BTreeMap<String, String>) -> Result<Self> {
+        Ok(Self {
+            query_id: fields.get("query_id").cloned(),
+            user: json_field(fields, "user")?,
+            receiver: json_field(fields, "receiver")?,
+            chat: json_field(fields, "chat")?,
+            chat_type: fields.get("chat_type").cloned(),
+            chat_instance: fields.get("chat_instance").cloned(),
+            start_param: fields.get("start_param").cloned(),
+            can_send_after: number_field(fields, "can_send_after")?,
+            auth_date: number_field(fields, "auth_date")?
+                .ok_or(Error::MissingField("auth_date"))?,
+            hash: fields
+                .get("hash")
+                .cloned()
+                .ok_or(Error::MissingField("hash"))?,
+        })
+    }
+}
+
+fn parse_fields(init_data: &str) -> BTreeMap<String, String> {
+    form_urlencoded::parse(init_data.as_bytes())
+        .into_owned()
+        .collect()
+}
+
+fn json_field<T>(fields: &BTreeMap<String, String>, name: &'static str) -> Result<Option<T>>
+where
+    T: serde::de::DeserializeOwned,
+{
+    fields
+        .get(name)
+        .map(|value| serde_json::from_str(value))
+        .transpose()
+        .map_err(|e| Error::InvalidField(name, e.to_string()))
+}
+
+fn number_field(fields: &BTreeMap<String, String>, name: &'static str) -> Result<Option<u64>> {
+    fields
+        .get(name)
+        .map(|value| value.parse::<u64>())
+        .transpose()
+        .map_err(|e| Error::InvalidField(name, e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signature::tests::{now, signed_query, BOT_TOKEN};
+
+    fn sign(fields: &[(&str, String)]) -> String {
+        let secret_key = signature::hmac_sha256(b"WebAppData", BOT_TOKEN.as_bytes());
+        signed_query(fields, &secret_key)
+    }
+
+    #[test]
+    fn validate_init_data() {
+        let init_data = sign(&[
+            ("query_id", "AAHdF6IQAAAAAN0XohDhrOrc".to_owned()),
+            (
+                "user",
+                r#"{"id":279058397,"first_name":"Vladislav","username":"vdkfrost","language_code":"en"}"#
+                    .to_owned(),
+            ),
+            ("auth_date", now().to_string()),
+            ("start_param", "ref-42".to_owned()),
+        ]);
+        let data =
+            WebAppInitData::validate(&init_data, BOT_TOKEN, Duration::from_secs(60)).unwrap();
+        assert_eq!(data.query_id.as_deref(), Some("AAHdF6IQAAAAAN0XohDhrOrc"));
+        assert_eq!(data.user.unwrap().id, 279058397);
+        assert_eq!(data.start_param.as_deref(), Some("ref-42"));
+
+        assert!(matches!(
+            WebAppInitData::validate(&init_data, "654321:other", Duration::from_secs(60)),
+            Err(Error::InvalidHash)
+        ));
+
+        let tampered = init_data.replace("ref-42", "ref-43");
+        assert!(matches!(
+            WebAppInitData::validate(&tampered, BOT_TOKEN, Duration::from_secs(60)),
+            Err(Error::InvalidHash)
+        ));
+    }
+
+    #[test]
+    fn expired_init_data() {
+        let init_data = sign(&[("auth_date", (now() - 3600).to_string())]);
+        assert!(matches!(
+            WebAppInitData::validate(&init_data, BOT_TOKEN, Duration::from_secs(60)),
+            Err(Error::Expired)
+        ));
+        assert!(WebAppInitData::validate(&init_data, BOT_TOKEN, Duration::from_secs(7200)).is_ok());
+    }
+}